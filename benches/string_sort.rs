@@ -0,0 +1,96 @@
+//! Compares the general-purpose sorts with the string-specialized ones on URL and file path
+//! datasets, which share long prefixes.
+//!
+//! Run with `cargo bench --bench string_sort`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use algorithm_playground::{merge_sort, multikey_quick_sort, quick_sort, string_radix_sort};
+
+/// Generates pseudo-random numbers with a xorshift generator.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
+
+/// Builds URLs on a handful of hosts, with deep, mostly shared paths and query strings.
+fn urls(len: usize) -> Vec<String> {
+    let hosts = ["www.example.com", "shop.example.com", "docs.example.org", "cdn.example.net"];
+    let sections = ["products", "blog", "api/v2/items", "static/assets/images"];
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    (0..len)
+        .map(|_| {
+            format!(
+                "https://{}/{}/category-{}/item-{:06}?ref=campaign-{}&page={}",
+                hosts[rng.below(hosts.len() as u64) as usize],
+                sections[rng.below(sections.len() as u64) as usize],
+                rng.below(20),
+                rng.below(1_000_000),
+                rng.below(5),
+                rng.below(50)
+            )
+        })
+        .collect()
+}
+
+/// Builds file paths in a source tree, many of which share a long directory prefix.
+fn paths(len: usize) -> Vec<String> {
+    let roots = ["/home/alice/projects", "/home/bob/work/src", "/usr/local/share/packages"];
+    let files = ["mod.rs", "lib.rs", "main.rs", "README.md", "Cargo.toml"];
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    (0..len)
+        .map(|_| {
+            format!(
+                "{}/crate-{}/src/module-{}/submodule-{}/{}",
+                roots[rng.below(roots.len() as u64) as usize],
+                rng.below(30),
+                rng.below(40),
+                rng.below(500),
+                files[rng.below(files.len() as u64) as usize]
+            )
+        })
+        .collect()
+}
+
+/// Returns the fastest of several runs of `sort` on fresh copies of `data`.
+fn time(data: &[String], sort: fn(&mut [String])) -> Duration {
+    (0..5)
+        .map(|_| {
+            let mut arr = data.to_vec();
+            let start = Instant::now();
+            sort(black_box(&mut arr));
+            let elapsed = start.elapsed();
+            assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+            elapsed
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    type StringSort = fn(&mut [String]);
+    let sorts: [(&str, StringSort); 4] = [
+        ("quick_sort", quick_sort),
+        ("merge_sort", merge_sort),
+        ("multikey_quick_sort", multikey_quick_sort),
+        ("string_radix_sort", string_radix_sort),
+    ];
+    let datasets = [("urls", urls(200_000)), ("paths", paths(200_000))];
+
+    for (dataset, data) in &datasets {
+        println!("{} ({} strings)", dataset, data.len());
+        let baseline = time(data, quick_sort);
+        for (name, sort) in sorts {
+            let elapsed = time(data, sort);
+            let speedup = baseline.as_secs_f64() / elapsed.as_secs_f64();
+            println!("  {:<20} {:>10.2?}  {:>5.2}x", name, elapsed, speedup);
+        }
+    }
+}
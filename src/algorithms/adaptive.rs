@@ -0,0 +1,236 @@
+//! # Adaptive
+//!
+//! `adaptive` looks at a slice before sorting it and hands it to whichever of the sorting
+//! algorithms suits it best.
+
+pub mod adaptive {
+    use std::cmp::Ordering;
+    use std::mem;
+
+    use crate::algorithms::sorting::sorting::{
+        heap_sort_by, in_place_merge_sort_by, insertion_sort_by, merge_sort_by, quick_sort_by,
+    };
+    use crate::algorithms::timsort::timsort::tim_sort_by;
+
+    /// The number of elements sampled from the slice.
+    const SAMPLE_SIZE: usize = 64;
+
+    /// Slices of at most this length are insertion sorted outright. Elements larger than
+    /// `LARGE_ELEMENT` bytes get a quarter of it, since insertion sort moves them so often.
+    const TINY_LEN: usize = 32;
+
+    /// Slices that look nearly sorted are insertion sorted up to this length. Beyond it a
+    /// misleading sample would cost too much, so they go to TimSort instead, which is just as
+    /// quick on nearly sorted input and cannot degrade to quadratic time.
+    const NEARLY_SORTED_INSERTION_LEN: usize = 1024;
+
+    /// Elements larger than this many bytes are expensive to move.
+    const LARGE_ELEMENT: usize = 64;
+
+    /// The algorithm [`smart_sort`] picked.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum SortStrategy {
+        /// [`insertion_sort`](crate::algorithms::sorting::sorting::insertion_sort), for tiny or
+        /// nearly sorted slices.
+        InsertionSort,
+        /// [`tim_sort`](crate::algorithms::timsort::timsort::tim_sort), for long nearly sorted
+        /// slices.
+        TimSort,
+        /// [`merge_sort`](crate::algorithms::sorting::sorting::merge_sort), when the sort must
+        /// be stable or the slice has many duplicates.
+        MergeSort,
+        /// [`in_place_merge_sort`](crate::algorithms::sorting::sorting::in_place_merge_sort),
+        /// when the sort must be stable and must not allocate.
+        InPlaceMergeSort,
+        /// [`heap_sort`](crate::algorithms::sorting::sorting::heap_sort), when the sort must not
+        /// allocate.
+        HeapSort,
+        /// [`quick_sort`](crate::algorithms::sorting::sorting::quick_sort), for everything else.
+        QuickSort,
+    }
+
+    /// Constraints on the algorithm [`smart_sort_by`] may pick.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct SortOptions {
+        /// Equal elements must keep their relative order.
+        pub stable: bool,
+        /// The sort must not allocate memory proportional to the input. Recursion of O(log n)
+        /// depth is allowed, as for [`Sorter::is_in_place`].
+        ///
+        /// [`Sorter::is_in_place`]: crate::algorithms::sorter::sorter::Sorter::is_in_place
+        pub constant_memory: bool,
+    }
+
+    /// Sorts a slice with whichever algorithm suits it best, and returns the one it used.
+    ///
+    /// See [`smart_sort_by`] for how the algorithm is chosen. Neither stability nor constant
+    /// memory use is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::adaptive::adaptive::{smart_sort, SortStrategy};
+    ///
+    /// let mut arr = [3, 1, 2];
+    /// assert_eq!(smart_sort(&mut arr), SortStrategy::InsertionSort);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn smart_sort<T: Ord>(arr: &mut [T]) -> SortStrategy {
+        smart_sort_by(arr, &SortOptions::default(), T::cmp)
+    }
+
+    /// Sorts a slice with a comparator function, using whichever algorithm suits it best within
+    /// `options`, and returns the one it used.
+    ///
+    /// A sample of the slice is examined first, taking a few hundred comparisons at most:
+    ///
+    /// - Tiny slices are insertion sorted.
+    /// - Slices that look nearly sorted are insertion sorted if they are short. Longer ones are
+    ///   TimSorted, unless the sort must not allocate, in which case they are heap sorted, or
+    ///   in-place merge sorted if the sort must also be stable.
+    /// - If the sort must be stable, merge sort is used, or in-place merge sort if it must also
+    ///   not allocate.
+    /// - If the sort must not allocate, heap sort is used.
+    /// - Slices with many duplicates are merge sorted, since quick sort's two-way partition
+    ///   handles them poorly.
+    /// - Everything else is quick sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::adaptive::adaptive::{smart_sort_by, SortOptions, SortStrategy};
+    ///
+    /// let mut arr: Vec<u32> = (0..1000).map(|i| i * 7919 % 1000).collect();
+    /// let options = SortOptions { stable: false, constant_memory: true };
+    /// assert_eq!(smart_sort_by(&mut arr, &options, |a, b| b.cmp(a)), SortStrategy::HeapSort);
+    /// assert!(arr.windows(2).all(|w| w[0] >= w[1]));
+    /// ```
+    pub fn smart_sort_by<T, F>(arr: &mut [T], options: &SortOptions, mut compare: F) -> SortStrategy
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let strategy = choose_strategy(arr, options, &mut compare);
+        match strategy {
+            SortStrategy::InsertionSort => insertion_sort_by(arr, compare),
+            SortStrategy::TimSort => tim_sort_by(arr, compare),
+            SortStrategy::MergeSort => merge_sort_by(arr, compare),
+            SortStrategy::InPlaceMergeSort => in_place_merge_sort_by(arr, compare),
+            SortStrategy::HeapSort => heap_sort_by(arr, compare),
+            SortStrategy::QuickSort => quick_sort_by(arr, compare),
+        }
+        strategy
+    }
+
+    /// Sorts a slice with a key extraction function, using whichever algorithm suits it best
+    /// within `options`, and returns the one it used. See [`smart_sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::adaptive::adaptive::{smart_sort_by_key, SortOptions, SortStrategy};
+    ///
+    /// let mut arr: Vec<(u32, usize)> = (0..1000).map(|i| (i % 3, i as usize)).collect();
+    /// let options = SortOptions { stable: true, constant_memory: false };
+    /// assert_eq!(smart_sort_by_key(&mut arr, &options, |&(key, _)| key), SortStrategy::MergeSort);
+    /// assert_eq!(arr[..2], [(0, 0), (0, 3)]);
+    /// ```
+    pub fn smart_sort_by_key<T, K, F>(arr: &mut [T], options: &SortOptions, mut f: F) -> SortStrategy
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        smart_sort_by(arr, options, |a, b| f(a).cmp(&f(b)))
+    }
+
+    fn choose_strategy<T, F>(arr: &[T], options: &SortOptions, compare: &mut F) -> SortStrategy
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let large_elements = mem::size_of::<T>() > LARGE_ELEMENT;
+        let tiny_len = if large_elements { TINY_LEN / 4 } else { TINY_LEN };
+        if len <= tiny_len {
+            return SortStrategy::InsertionSort;
+        }
+
+        let sample = Sample::take(arr, compare);
+        if sample.nearly_sorted() {
+            if len <= NEARLY_SORTED_INSERTION_LEN && !large_elements {
+                return SortStrategy::InsertionSort;
+            }
+            return match (options.stable, options.constant_memory) {
+                (_, false) => SortStrategy::TimSort,
+                (true, true) => SortStrategy::InPlaceMergeSort,
+                (false, true) => SortStrategy::HeapSort,
+            };
+        }
+
+        match (options.stable, options.constant_memory) {
+            (true, false) => SortStrategy::MergeSort,
+            (true, true) => SortStrategy::InPlaceMergeSort,
+            (false, true) => SortStrategy::HeapSort,
+            (false, false) if sample.many_duplicates() => SortStrategy::MergeSort,
+            (false, false) => SortStrategy::QuickSort,
+        }
+    }
+
+    /// What a sample of evenly spaced elements says about a slice.
+    struct Sample {
+        /// The number of elements sampled.
+        size: usize,
+        /// How many sampled elements are greater than the element right after them.
+        local_descents: usize,
+        /// How many sampled elements are greater than the next sampled element.
+        global_descents: usize,
+        /// How many sampled elements are equal to another sampled element.
+        duplicates: usize,
+    }
+
+    impl Sample {
+        fn take<T, F>(arr: &[T], compare: &mut F) -> Self
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            let len = arr.len();
+            let size = SAMPLE_SIZE.min(len);
+            let positions: Vec<usize> = (0..size).map(|k| k * (len - 1) / (size - 1)).collect();
+
+            let mut local_descents = 0;
+            for &i in &positions[..size - 1] {
+                if compare(&arr[i], &arr[i + 1]) == Ordering::Greater {
+                    local_descents += 1;
+                }
+            }
+            let global_descents = positions
+                .windows(2)
+                .filter(|w| compare(&arr[w[0]], &arr[w[1]]) == Ordering::Greater)
+                .count();
+
+            // Sort the sampled positions by their elements to find the equal ones
+            let mut sorted = positions;
+            insertion_sort_by(&mut sorted, |&i, &j| compare(&arr[i], &arr[j]));
+            let duplicates = sorted
+                .windows(2)
+                .filter(|w| compare(&arr[w[0]], &arr[w[1]]) == Ordering::Equal)
+                .count();
+
+            Sample {
+                size,
+                local_descents,
+                global_descents,
+                duplicates,
+            }
+        }
+
+        /// Whether the sampled elements are in order, and at most one in sixteen of them is
+        /// followed by a smaller neighbour.
+        fn nearly_sorted(&self) -> bool {
+            self.global_descents == 0 && self.local_descents * 16 <= self.size
+        }
+
+        /// Whether at least half of the sampled elements repeat another one.
+        fn many_duplicates(&self) -> bool {
+            self.duplicates * 2 >= self.size
+        }
+    }
+}
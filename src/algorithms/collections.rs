@@ -0,0 +1,236 @@
+//! # Collections
+//!
+//! `collections` sorts the standard collections that are not stored as a single slice, without
+//! copying their elements out into one.
+
+pub mod collections {
+    use std::cmp::Ordering;
+    use std::collections::{LinkedList, VecDeque};
+    use std::mem;
+
+    use crate::algorithms::sorting::sorting::{merge_runs, merge_sort_impl, NoObserver, SortOps};
+
+    /// Sorts a linked list using a bottom-up merge sort.
+    ///
+    /// Nodes are unlinked from the list one at a time and merged into sorted lists of 1, 2, 4,
+    /// ... nodes, like counting in binary, and finally into one. Only the links between nodes
+    /// change: no element is cloned or moved and nothing is allocated, apart from a vector of
+    /// O(log n) list heads. Takes O(n log n) time. The sort is stable.
+    ///
+    /// If the comparator panics, the list still holds every element, in some order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::LinkedList;
+    /// use algorithm_playground::algorithms::collections::collections::sort_linked_list;
+    ///
+    /// let mut list = LinkedList::from([3, 1, 2]);
+    /// sort_linked_list(&mut list);
+    /// assert!(list.into_iter().eq([1, 2, 3]));
+    /// ```
+    pub fn sort_linked_list<T: Ord>(list: &mut LinkedList<T>) {
+        sort_linked_list_by(list, T::cmp);
+    }
+
+    /// Sorts a linked list using a bottom-up merge sort with a comparator function. See
+    /// [`sort_linked_list`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::LinkedList;
+    /// use algorithm_playground::algorithms::collections::collections::sort_linked_list_by;
+    ///
+    /// let mut list = LinkedList::from([1, 2, 3]);
+    /// sort_linked_list_by(&mut list, |a, b| b.cmp(a));
+    /// assert!(list.into_iter().eq([3, 2, 1]));
+    /// ```
+    pub fn sort_linked_list_by<T, F>(list: &mut LinkedList<T>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut pending = PendingLists {
+            list,
+            bins: Vec::new(),
+            carry: LinkedList::new(),
+            merged: LinkedList::new(),
+        };
+
+        // `bins[i]` is either empty or a sorted list of 2^i nodes, all of which came before the
+        // nodes in the bins below it
+        while !pending.list.is_empty() {
+            pending.carry = take_front(pending.list);
+            let mut i = 0;
+            while i < pending.bins.len() && !pending.bins[i].is_empty() {
+                merge_lists(&mut pending.bins[i], &mut pending.carry, &mut pending.merged, &mut compare);
+                mem::swap(&mut pending.carry, &mut pending.merged);
+                i += 1;
+            }
+            if i == pending.bins.len() {
+                pending.bins.push(mem::take(&mut pending.carry));
+            } else {
+                mem::swap(&mut pending.bins[i], &mut pending.carry);
+            }
+        }
+
+        for i in 0..pending.bins.len() {
+            merge_lists(&mut pending.bins[i], &mut pending.carry, &mut pending.merged, &mut compare);
+            mem::swap(&mut pending.carry, &mut pending.merged);
+        }
+        pending.list.append(&mut pending.carry);
+    }
+
+    /// Sorts a linked list using a bottom-up merge sort with a key extraction function. See
+    /// [`sort_linked_list`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::LinkedList;
+    /// use algorithm_playground::algorithms::collections::collections::sort_linked_list_by_key;
+    ///
+    /// let mut list = LinkedList::from(["ccc", "a", "bb"]);
+    /// sort_linked_list_by_key(&mut list, |s| s.len());
+    /// assert!(list.into_iter().eq(["a", "bb", "ccc"]));
+    /// ```
+    pub fn sort_linked_list_by_key<T, K, F>(list: &mut LinkedList<T>, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort_linked_list_by(list, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// The lists a linked list sort has taken nodes out into. If the comparator panics, they
+    /// are all appended back onto the list when this is dropped, so that no element is lost.
+    struct PendingLists<'a, T> {
+        list: &'a mut LinkedList<T>,
+        bins: Vec<LinkedList<T>>,
+        carry: LinkedList<T>,
+        merged: LinkedList<T>,
+    }
+
+    impl<T> Drop for PendingLists<'_, T> {
+        fn drop(&mut self) {
+            for bin in &mut self.bins {
+                self.list.append(bin);
+            }
+            self.list.append(&mut self.carry);
+            self.list.append(&mut self.merged);
+        }
+    }
+
+    /// Unlinks the first node of a non-empty list and returns it as a list of its own.
+    fn take_front<T>(list: &mut LinkedList<T>) -> LinkedList<T> {
+        let rest = list.split_off(1);
+        mem::replace(list, rest)
+    }
+
+    /// Moves the nodes of the sorted lists `a` and `b` onto the end of `out` in sorted order,
+    /// taking from `a` first when elements are equal, and leaves `a` and `b` empty.
+    fn merge_lists<T, F>(a: &mut LinkedList<T>, b: &mut LinkedList<T>, out: &mut LinkedList<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        while let (Some(x), Some(y)) = (a.front(), b.front()) {
+            let source = if compare(y, x) == Ordering::Less { &mut *b } else { &mut *a };
+            out.append(&mut take_front(source));
+        }
+        out.append(a);
+        out.append(b);
+    }
+
+    /// Sorts a `VecDeque` in place, without first making its ring buffer contiguous.
+    ///
+    /// The elements of a `VecDeque` may wrap around the end of its buffer, leaving them in two
+    /// slices. Each slice is merge sorted, then the largest elements of the first are swapped
+    /// with the smallest elements of the second, found by binary search, so that everything in
+    /// the first slice belongs before everything in the second. That leaves each slice as two
+    /// sorted runs to merge. Takes O(n log n) time and a scratch buffer of at most n / 2
+    /// elements, and the elements stay in the same two slices of the buffer. The sort is
+    /// stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use algorithm_playground::algorithms::collections::collections::sort_vec_deque;
+    ///
+    /// let mut deque = VecDeque::from([4, 5, 6]);
+    /// deque.push_front(2);
+    /// deque.push_front(7);
+    /// sort_vec_deque(&mut deque);
+    /// assert_eq!(deque, [2, 4, 5, 6, 7]);
+    /// ```
+    pub fn sort_vec_deque<T: Ord>(deque: &mut VecDeque<T>) {
+        sort_vec_deque_by(deque, T::cmp);
+    }
+
+    /// Sorts a `VecDeque` in place with a comparator function. See [`sort_vec_deque`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use algorithm_playground::algorithms::collections::collections::sort_vec_deque_by;
+    ///
+    /// let mut deque = VecDeque::from([1, 2, 3]);
+    /// sort_vec_deque_by(&mut deque, |a, b| b.cmp(a));
+    /// assert_eq!(deque, [3, 2, 1]);
+    /// ```
+    pub fn sort_vec_deque_by<T, F>(deque: &mut VecDeque<T>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (front, back) = deque.as_mut_slices();
+        let mut buf = Vec::new();
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut compare, &mut observer);
+        merge_sort_impl(front, &mut buf, &mut ops);
+        merge_sort_impl(back, &mut buf, &mut ops);
+
+        // Find how many elements have to cross over: the most `k` for which the k-th largest
+        // element of `front` is still greater than the k-th smallest of `back`
+        let front_len = front.len();
+        let (mut crossing, mut most) = (0, front_len.min(back.len()));
+        while crossing < most {
+            let k = crossing + (most - crossing).div_ceil(2);
+            let (i, j) = (front_len - k, k - 1);
+            if ops.compare_values(&front[i], i, &back[j], front_len + j) == Ordering::Greater {
+                crossing = k;
+            } else {
+                most = k - 1;
+            }
+        }
+        if crossing == 0 {
+            return;
+        }
+
+        // Equal elements never cross, so swapping whole blocks keeps the sort stable
+        front[front_len - crossing..].swap_with_slice(&mut back[..crossing]);
+        buf.reserve(crossing);
+        merge_runs(front, front_len - crossing, &mut buf, &mut ops);
+        merge_runs(back, crossing, &mut buf, &mut ops);
+    }
+
+    /// Sorts a `VecDeque` in place with a key extraction function. See [`sort_vec_deque`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use algorithm_playground::algorithms::collections::collections::sort_vec_deque_by_key;
+    ///
+    /// let mut deque = VecDeque::from([-3, 1, -2]);
+    /// sort_vec_deque_by_key(&mut deque, |x: &i32| x.abs());
+    /// assert_eq!(deque, [1, -2, -3]);
+    /// ```
+    pub fn sort_vec_deque_by_key<T, K, F>(deque: &mut VecDeque<T>, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        sort_vec_deque_by(deque, |a, b| f(a).cmp(&f(b)));
+    }
+}
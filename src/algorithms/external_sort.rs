@@ -0,0 +1,405 @@
+//! # External Sort
+//!
+//! `external_sort` sorts files of records that are too large to fit in memory, by sorting
+//! memory-sized chunks and merging them back together from temporary files.
+
+pub mod external_sort {
+    use std::cmp::Ordering;
+    use std::fs::{self, File};
+    use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+    use std::mem;
+    use std::path::{Path, PathBuf};
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+    use crate::algorithms::sorting::sorting::{heapify, merge_sort_by, NoObserver, SortOps};
+
+    /// Reads and writes the records of a file.
+    pub trait RecordCodec {
+        /// The in-memory form of a record.
+        type Record;
+
+        /// Reads the next record, or returns `None` at the end of the input.
+        fn read_record<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<Self::Record>>;
+
+        /// Writes a record so that `read_record` will read it back unchanged.
+        fn write_record<W: Write>(&self, writer: &mut W, record: &Self::Record) -> io::Result<()>;
+
+        /// Estimates how many bytes of memory a record takes up, which is counted against the
+        /// memory budget.
+        fn memory_size(&self, record: &Self::Record) -> usize;
+    }
+
+    /// Newline-delimited records, read as bytes without their trailing `\n`.
+    ///
+    /// A final line without a trailing newline is still read as a record. Every record is
+    /// written back followed by a newline.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct LineCodec;
+
+    impl RecordCodec for LineCodec {
+        type Record = Vec<u8>;
+
+        fn read_record<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+            let mut line = Vec::new();
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                return Ok(None);
+            }
+            if line.last() == Some(&b'\n') {
+                line.pop();
+            }
+            Ok(Some(line))
+        }
+
+        fn write_record<W: Write>(&self, writer: &mut W, record: &Vec<u8>) -> io::Result<()> {
+            writer.write_all(record)?;
+            writer.write_all(b"\n")
+        }
+
+        fn memory_size(&self, record: &Vec<u8>) -> usize {
+            mem::size_of::<Vec<u8>>() + record.len()
+        }
+    }
+
+    /// Binary records of exactly `width` bytes each, with no separators.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct FixedWidthCodec {
+        /// The length of every record in bytes.
+        pub width: usize,
+    }
+
+    impl FixedWidthCodec {
+        /// Creates a codec for records of `width` bytes.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `width` is zero.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use algorithm_playground::algorithms::external_sort::external_sort::FixedWidthCodec;
+        ///
+        /// let codec = FixedWidthCodec::new(8);
+        /// assert_eq!(codec.width, 8);
+        /// ```
+        pub fn new(width: usize) -> Self {
+            assert!(width > 0, "fixed-width records must be at least one byte long");
+            FixedWidthCodec { width }
+        }
+    }
+
+    impl RecordCodec for FixedWidthCodec {
+        type Record = Vec<u8>;
+
+        /// Reads the next record, failing with `ErrorKind::InvalidData` if the input ends partway
+        /// through one.
+        fn read_record<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+            let mut record = Vec::with_capacity(self.width);
+            let read = reader.by_ref().take(self.width as u64).read_to_end(&mut record)?;
+            match read {
+                0 => Ok(None),
+                n if n == self.width => Ok(Some(record)),
+                n => Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("input ends with a partial record of {} of {} bytes", n, self.width),
+                )),
+            }
+        }
+
+        fn write_record<W: Write>(&self, writer: &mut W, record: &Vec<u8>) -> io::Result<()> {
+            if record.len() != self.width {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("record is {} bytes long instead of {}", record.len(), self.width),
+                ));
+            }
+            writer.write_all(record)
+        }
+
+        fn memory_size(&self, _record: &Vec<u8>) -> usize {
+            mem::size_of::<Vec<u8>>() + self.width
+        }
+    }
+
+    /// Controls how much memory an external sort uses and where it keeps its temporary files.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ExternalSortConfig {
+        /// The number of bytes of records to hold in memory at once, as measured by
+        /// [`RecordCodec::memory_size`]. At least one record is always held.
+        pub memory_budget: usize,
+        /// The most sorted runs merged together at once, which bounds the number of files open
+        /// at the same time. Values below two are treated as two.
+        pub merge_fan_in: usize,
+        /// The directory the sorted runs are written to. They are removed once the sort
+        /// finishes, whether it succeeds or not.
+        pub temp_dir: PathBuf,
+    }
+
+    impl ExternalSortConfig {
+        /// Creates a configuration with the given memory budget in bytes, merging up to 64
+        /// runs at once in the system's temporary directory.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use algorithm_playground::algorithms::external_sort::external_sort::ExternalSortConfig;
+        ///
+        /// let config = ExternalSortConfig::new(1 << 20);
+        /// assert_eq!(config.memory_budget, 1 << 20);
+        /// ```
+        pub fn new(memory_budget: usize) -> Self {
+            ExternalSortConfig {
+                memory_budget,
+                merge_fan_in: 64,
+                temp_dir: std::env::temp_dir(),
+            }
+        }
+    }
+
+    impl Default for ExternalSortConfig {
+        /// Uses a memory budget of 64 MiB.
+        fn default() -> Self {
+            ExternalSortConfig::new(64 << 20)
+        }
+    }
+
+    /// What an external sort did.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct ExternalSortReport {
+        /// The number of records sorted.
+        pub records: usize,
+        /// The number of sorted runs spilled to temporary files by the first pass, or zero if
+        /// the whole input fit within the memory budget.
+        pub runs: usize,
+        /// The number of merge passes over the runs.
+        pub merge_passes: usize,
+    }
+
+    /// Sorts the records in the file at `input` and writes them to the file at `output`.
+    ///
+    /// The input is read in chunks that fit within the memory budget, and each chunk is sorted
+    /// with [`merge_sort_by`] and spilled to a temporary file as a sorted run. The runs are then
+    /// merged with a k-way merge, in several passes if there are more of them than the merge
+    /// fan-in. The input has been read completely before the output is created, so the two
+    /// may be the same file. The sort is stable.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading the input, writing the output or the temporary files, or
+    /// from the codec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::external_sort::external_sort::{
+    ///     external_sort, ExternalSortConfig, LineCodec,
+    /// };
+    ///
+    /// let path = std::env::temp_dir().join(format!("external-sort-doc-{}.txt", std::process::id()));
+    /// std::fs::write(&path, "pear\napple\nfig\n").unwrap();
+    ///
+    /// let report = external_sort(&path, &path, &LineCodec, &ExternalSortConfig::new(64)).unwrap();
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), "apple\nfig\npear\n");
+    /// assert_eq!(report.records, 3);
+    /// std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn external_sort<C>(
+        input: &Path,
+        output: &Path,
+        codec: &C,
+        config: &ExternalSortConfig,
+    ) -> io::Result<ExternalSortReport>
+    where
+        C: RecordCodec,
+        C::Record: Ord,
+    {
+        external_sort_by(input, output, codec, config, C::Record::cmp)
+    }
+
+    /// Sorts the records in the file at `input` with a comparator function and writes them to
+    /// the file at `output`. See [`external_sort`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading the input, writing the output or the temporary files, or
+    /// from the codec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::external_sort::external_sort::{
+    ///     external_sort_by, ExternalSortConfig, FixedWidthCodec,
+    /// };
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let input = dir.join(format!("external-sort-doc-{}.in", std::process::id()));
+    /// let output = dir.join(format!("external-sort-doc-{}.out", std::process::id()));
+    /// std::fs::write(&input, [1, 0, 3, 0, 2, 0]).unwrap();
+    ///
+    /// let codec = FixedWidthCodec::new(2);
+    /// external_sort_by(&input, &output, &codec, &ExternalSortConfig::default(), |a, b| b.cmp(a)).unwrap();
+    /// assert_eq!(std::fs::read(&output).unwrap(), [3, 0, 2, 0, 1, 0]);
+    /// std::fs::remove_file(&input).unwrap();
+    /// std::fs::remove_file(&output).unwrap();
+    /// ```
+    pub fn external_sort_by<C, F>(
+        input: &Path,
+        output: &Path,
+        codec: &C,
+        config: &ExternalSortConfig,
+        mut compare: F,
+    ) -> io::Result<ExternalSortReport>
+    where
+        C: RecordCodec,
+        F: FnMut(&C::Record, &C::Record) -> Ordering,
+    {
+        let mut reader = BufReader::new(File::open(input)?);
+        let mut report = ExternalSortReport::default();
+        let mut runs = Vec::new();
+
+        loop {
+            let (mut chunk, finished) = read_chunk(&mut reader, codec, config.memory_budget)?;
+            report.records += chunk.len();
+            merge_sort_by(&mut chunk, &mut compare);
+
+            // Skip the temporary files entirely if everything fit in one chunk
+            if finished && runs.is_empty() {
+                let mut writer = BufWriter::new(File::create(output)?);
+                for record in &chunk {
+                    codec.write_record(&mut writer, record)?;
+                }
+                writer.flush()?;
+                return Ok(report);
+            }
+
+            if !chunk.is_empty() {
+                let run = TempRun::create(&config.temp_dir)?;
+                let mut writer = BufWriter::new(File::create(&run.path)?);
+                for record in &chunk {
+                    codec.write_record(&mut writer, record)?;
+                }
+                writer.flush()?;
+                runs.push(run);
+            }
+            if finished {
+                break;
+            }
+        }
+        drop(reader);
+        report.runs = runs.len();
+
+        // Merge groups of runs into longer runs until a single merge can produce the output
+        let fan_in = config.merge_fan_in.max(2);
+        while runs.len() > fan_in {
+            let mut merged = Vec::with_capacity(runs.len().div_ceil(fan_in));
+            for group in runs.chunks(fan_in) {
+                let run = TempRun::create(&config.temp_dir)?;
+                merge_runs(group, &run.path, codec, &mut compare)?;
+                merged.push(run);
+            }
+            runs = merged;
+            report.merge_passes += 1;
+        }
+        merge_runs(&runs, output, codec, &mut compare)?;
+        report.merge_passes += 1;
+        Ok(report)
+    }
+
+    /// Reads records until the memory budget is used up or the input ends, and returns them
+    /// along with whether the input ended.
+    fn read_chunk<C, R>(reader: &mut R, codec: &C, memory_budget: usize) -> io::Result<(Vec<C::Record>, bool)>
+    where
+        C: RecordCodec,
+        R: BufRead,
+    {
+        let mut chunk = Vec::new();
+        let mut used = 0;
+        while chunk.is_empty() || used < memory_budget {
+            match codec.read_record(reader)? {
+                Some(record) => {
+                    used += codec.memory_size(&record);
+                    chunk.push(record);
+                }
+                None => return Ok((chunk, true)),
+            }
+        }
+        Ok((chunk, false))
+    }
+
+    /// Merges sorted run files into one sorted file with a k-way merge.
+    ///
+    /// The next record of every run is kept in a heap ordered by the record and then by run,
+    /// so that equal records come out in the order of their runs and the merge is stable.
+    fn merge_runs<C, F>(runs: &[TempRun], output: &Path, codec: &C, compare: &mut F) -> io::Result<()>
+    where
+        C: RecordCodec,
+        F: FnMut(&C::Record, &C::Record) -> Ordering,
+    {
+        let mut readers = Vec::with_capacity(runs.len());
+        let mut heap = Vec::with_capacity(runs.len());
+        for (index, run) in runs.iter().enumerate() {
+            let mut reader = BufReader::new(File::open(&run.path)?);
+            if let Some(record) = codec.read_record(&mut reader)? {
+                heap.push((record, index));
+            }
+            readers.push(reader);
+        }
+
+        // A max-heap under the reversed order is a min-heap, with the next record on top
+        let mut reversed = |a: &(C::Record, usize), b: &(C::Record, usize)| {
+            compare(&b.0, &a.0).then(b.1.cmp(&a.1))
+        };
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut reversed, &mut observer);
+        let len = heap.len();
+        for i in (0..len / 2).rev() {
+            heapify(&mut heap, len, i, &mut ops);
+        }
+
+        let mut writer = BufWriter::new(File::create(output)?);
+        while let Some((record, index)) = heap.first() {
+            codec.write_record(&mut writer, record)?;
+
+            // Replace the record just written with the next one from the same run
+            let index = *index;
+            match codec.read_record(&mut readers[index])? {
+                Some(next) => heap[0] = (next, index),
+                None => {
+                    heap.swap_remove(0);
+                }
+            }
+            let len = heap.len();
+            heapify(&mut heap, len, 0, &mut ops);
+        }
+        writer.flush()
+    }
+
+    /// A sorted run in a temporary file, which is removed when the run is dropped.
+    struct TempRun {
+        path: PathBuf,
+    }
+
+    impl TempRun {
+        /// Creates an empty file with a name no other run in this process uses.
+        fn create(dir: &Path) -> io::Result<Self> {
+            static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+            loop {
+                let id = NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed);
+                let path = dir.join(format!("external-sort-{}-{}.run", process::id(), id));
+                // Never reuse a file left behind by another process
+                match File::options().write(true).create_new(true).open(&path) {
+                    Ok(_) => return Ok(TempRun { path }),
+                    Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+
+    impl Drop for TempRun {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
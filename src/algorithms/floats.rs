@@ -0,0 +1,219 @@
+//! # Floats
+//!
+//! `floats` sorts `f32` and `f64` values, which only implement `PartialOrd`, by the IEEE 754
+//! total order, with NaNs gathered at whichever end of the slice the caller chooses.
+
+pub mod floats {
+    use std::cmp::Ordering;
+
+    use crate::algorithms::radix::radix::radix_sort_by_key;
+    use crate::algorithms::sorter::sorter::{QuickSort, Sorter};
+
+    mod private {
+        use crate::algorithms::radix::radix::RadixKey;
+
+        /// Maps a float to an unsigned integer that sorts in its IEEE 754 total order, for
+        /// [`radix_sort_floats`](super::radix_sort_floats) only. Floats are deliberately not
+        /// `RadixKey`s themselves, since that would let them into `counting_sort`, whose count
+        /// table for the range of a float's bits could never be allocated.
+        pub trait TotalOrderBits: Copy {
+            type Bits: RadixKey;
+
+            /// Flips every bit of a negative float and just the sign bit of any other, which
+            /// puts `-NaN < -inf < ... < -0.0 < 0.0 < ... < inf < NaN`.
+            fn total_order_bits(self) -> Self::Bits;
+        }
+
+        macro_rules! impl_total_order_bits {
+            ($($t:ty => $u:ty),*) => {$(
+                impl TotalOrderBits for $t {
+                    type Bits = $u;
+
+                    fn total_order_bits(self) -> $u {
+                        let bits = self.to_bits();
+                        let sign = 1 << (<$u>::BITS - 1);
+                        if bits & sign == 0 { bits ^ sign } else { !bits }
+                    }
+                }
+            )*};
+        }
+
+        impl_total_order_bits!(f32 => u32, f64 => u64);
+    }
+
+    /// A floating-point type that can be sorted by its IEEE 754 total order.
+    pub trait Float: Copy + private::TotalOrderBits {
+        /// Compares two values by the IEEE 754 total order, as `f64::total_cmp` does.
+        fn total_cmp(&self, other: &Self) -> Ordering;
+
+        /// Returns whether the value is NaN.
+        fn is_nan(self) -> bool;
+    }
+
+    macro_rules! impl_float {
+        ($($t:ty),*) => {$(
+            impl Float for $t {
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+            }
+        )*};
+    }
+
+    impl_float!(f32, f64);
+
+    /// Where NaNs end up in a sorted slice.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub enum NanPlacement {
+        /// Before every other value.
+        First,
+        /// After every other value.
+        #[default]
+        Last,
+    }
+
+    /// Compares two floats by the IEEE 754 total order, except that NaNs of either sign go
+    /// wherever `nans` says.
+    ///
+    /// Among the other values, `-inf < ... < -0.0 < 0.0 < ... < inf`. NaNs are ordered among
+    /// themselves by the total order too, so negative NaNs come before positive ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use algorithm_playground::algorithms::floats::floats::{compare_floats, NanPlacement};
+    ///
+    /// assert_eq!(compare_floats(&-0.0, &0.0, NanPlacement::Last), Ordering::Less);
+    /// assert_eq!(compare_floats(&-f64::NAN, &1.0, NanPlacement::Last), Ordering::Greater);
+    /// assert_eq!(compare_floats(&f64::NAN, &1.0, NanPlacement::First), Ordering::Less);
+    /// ```
+    pub fn compare_floats<F: Float>(a: &F, b: &F, nans: NanPlacement) -> Ordering {
+        match (a.is_nan(), b.is_nan(), nans) {
+            (true, false, NanPlacement::First) | (false, true, NanPlacement::Last) => Ordering::Less,
+            (true, false, NanPlacement::Last) | (false, true, NanPlacement::First) => Ordering::Greater,
+            _ => a.total_cmp(b),
+        }
+    }
+
+    /// Sorts a slice of floats by the IEEE 754 total order, with NaNs placed as `nans` says.
+    ///
+    /// This uses [`radix_sort_floats`], which runs in linear time. See [`compare_floats`] for
+    /// the order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::floats::floats::{sort_floats, NanPlacement};
+    ///
+    /// let mut arr = [2.5, f64::NAN, -1.0, 0.0, -0.0];
+    /// sort_floats(&mut arr, NanPlacement::First);
+    /// assert!(arr[0].is_nan());
+    /// assert_eq!(arr[1..], [-1.0, -0.0, 0.0, 2.5]);
+    /// assert!(arr[2].is_sign_negative());
+    /// ```
+    pub fn sort_floats<F: Float>(arr: &mut [F], nans: NanPlacement) {
+        radix_sort_floats(arr, nans);
+    }
+
+    /// Sorts a slice of floats with the given sorting algorithm, by the IEEE 754 total order
+    /// with NaNs placed as `nans` says.
+    ///
+    /// Any of the algorithms in the sorting module can be used through its [`Sorter`]. See
+    /// [`compare_floats`] for the order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::floats::floats::{sort_floats_with, NanPlacement};
+    /// use algorithm_playground::algorithms::sorter::sorter::HeapSort;
+    ///
+    /// let mut arr = [3.0f32, f32::NAN, 1.0, f32::NEG_INFINITY];
+    /// sort_floats_with(&HeapSort, &mut arr, NanPlacement::Last);
+    /// assert_eq!(arr[..3], [f32::NEG_INFINITY, 1.0, 3.0]);
+    /// assert!(arr[3].is_nan());
+    /// ```
+    pub fn sort_floats_with<S, F>(sorter: &S, arr: &mut [F], nans: NanPlacement)
+    where
+        S: Sorter,
+        F: Float,
+    {
+        sorter.sort_by(arr, |a, b| compare_floats(a, b, nans));
+    }
+
+    /// Sorts a slice by a float key with the given sorting algorithm, by the IEEE 754 total
+    /// order with NaN keys placed as `nans` says. See [`sort_floats_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::floats::floats::{sort_by_float_key_with, NanPlacement};
+    /// use algorithm_playground::algorithms::sorter::sorter::MergeSort;
+    ///
+    /// let mut cities = [("Oslo", 5.2), ("Cairo", f64::NAN), ("Lima", 19.1), ("Nuuk", -7.4)];
+    /// sort_by_float_key_with(&MergeSort, &mut cities, |&(_, temp)| temp, NanPlacement::Last);
+    /// let names: Vec<&str> = cities.iter().map(|&(name, _)| name).collect();
+    /// assert_eq!(names, ["Nuuk", "Oslo", "Lima", "Cairo"]);
+    /// ```
+    pub fn sort_by_float_key_with<S, T, F, K>(sorter: &S, arr: &mut [T], mut key: K, nans: NanPlacement)
+    where
+        S: Sorter,
+        F: Float,
+        K: FnMut(&T) -> F,
+    {
+        sorter.sort_by(arr, |a, b| compare_floats(&key(a), &key(b), nans));
+    }
+
+    /// Sorts a slice by a float key using quick sort, by the IEEE 754 total order with NaN
+    /// keys placed as `nans` says. See [`sort_by_float_key_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::floats::floats::{sort_by_float_key, NanPlacement};
+    ///
+    /// let mut points = [(1, 0.5), (2, -3.0), (3, 2.0)];
+    /// sort_by_float_key(&mut points, |&(_, y)| y, NanPlacement::Last);
+    /// assert_eq!(points, [(2, -3.0), (1, 0.5), (3, 2.0)]);
+    /// ```
+    pub fn sort_by_float_key<T, F, K>(arr: &mut [T], key: K, nans: NanPlacement)
+    where
+        F: Float,
+        K: FnMut(&T) -> F,
+    {
+        sort_by_float_key_with(&QuickSort, arr, key, nans);
+    }
+
+    /// Sorts a slice of floats using LSD radix sort over their bits, by the IEEE 754 total
+    /// order with NaNs placed as `nans` says.
+    ///
+    /// The bits of every float are flipped so that they sort as unsigned integers in total
+    /// order, which leaves negative NaNs at the front and positive NaNs at the back. The NaNs
+    /// are then rotated to the chosen end. Runs in O(n) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::floats::floats::{radix_sort_floats, NanPlacement};
+    ///
+    /// let mut arr = [1.5, -f64::NAN, -2.0, f64::INFINITY];
+    /// radix_sort_floats(&mut arr, NanPlacement::Last);
+    /// assert_eq!(arr[..3], [-2.0, 1.5, f64::INFINITY]);
+    /// assert!(arr[3].is_nan());
+    /// ```
+    pub fn radix_sort_floats<F: Float>(arr: &mut [F], nans: NanPlacement) {
+        radix_sort_by_key(arr, |&x| x.total_order_bits());
+
+        let negative_nans = arr.iter().take_while(|x| x.is_nan()).count();
+        let positive_nans = arr[negative_nans..].iter().rev().take_while(|x| x.is_nan()).count();
+        let len = arr.len();
+        match nans {
+            NanPlacement::First => arr[negative_nans..].rotate_right(positive_nans),
+            NanPlacement::Last => arr[..len - positive_nans].rotate_left(negative_nans),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! # Instrumentation
+//!
+//! `instrumentation` runs the sorting algorithms while counting the work they do, so that they
+//! can be compared empirically.
+
+pub mod instrumentation {
+    use std::cmp::Ordering;
+
+    use crate::algorithms::sorting::sorting::{
+        bubble_sort_impl, heap_sort_impl, insertion_sort_impl, merge_sort_impl, quick_sort_impl,
+        selection_sort_impl, SortObserver, SortOps,
+    };
+
+    /// Counts of the basic operations a sort performed.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct SortStats {
+        /// The number of times two elements were compared.
+        pub comparisons: usize,
+        /// The number of times two elements were swapped.
+        pub swaps: usize,
+        /// The number of elements moved into the slice other than by a swap.
+        pub writes: usize,
+        /// The deepest nesting of recursive calls below the entry point, or zero for an
+        /// iterative algorithm. For heap sort this counts the calls to `heapify`.
+        pub max_depth: usize,
+    }
+
+    impl<T> SortObserver<T> for SortStats {
+        fn compare(&mut self, _i: usize, _j: usize) {
+            self.comparisons += 1;
+        }
+
+        fn swap(&mut self, _i: usize, _j: usize) {
+            self.swaps += 1;
+        }
+
+        fn write(&mut self, _index: usize, _value: &T) {
+            self.writes += 1;
+        }
+
+        fn enter(&mut self, depth: usize) {
+            self.max_depth = self.max_depth.max(depth);
+        }
+    }
+
+    /// Sorts a slice using merge sort and returns the operations it performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::instrumentation::instrumentation::merge_sort_instrumented;
+    ///
+    /// let mut arr = [4, 3, 2, 1];
+    /// let stats = merge_sort_instrumented(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4]);
+    /// assert_eq!(stats.swaps, 0);
+    /// assert_eq!(stats.max_depth, 2);
+    /// ```
+    pub fn merge_sort_instrumented<T: Ord>(arr: &mut [T]) -> SortStats {
+        merge_sort_instrumented_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using merge sort with a comparator function and returns the operations it
+    /// performed.
+    pub fn merge_sort_instrumented_by<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut stats = SortStats::default();
+        merge_sort_impl(arr, &mut Vec::new(), &mut SortOps::new(&mut compare, &mut stats));
+        stats
+    }
+
+    /// Sorts a slice using heap sort and returns the operations it performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::instrumentation::instrumentation::heap_sort_instrumented;
+    ///
+    /// let mut arr = [4, 3, 2, 1];
+    /// let stats = heap_sort_instrumented(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4]);
+    /// assert_eq!(stats.writes, 0);
+    /// ```
+    pub fn heap_sort_instrumented<T: Ord>(arr: &mut [T]) -> SortStats {
+        heap_sort_instrumented_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using heap sort with a comparator function and returns the operations it
+    /// performed.
+    pub fn heap_sort_instrumented_by<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut stats = SortStats::default();
+        heap_sort_impl(arr, &mut SortOps::new(&mut compare, &mut stats));
+        stats
+    }
+
+    /// Sorts a slice using quick sort and returns the operations it performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::instrumentation::instrumentation::quick_sort_instrumented;
+    ///
+    /// let mut arr: Vec<u32> = (0..1000).rev().collect();
+    /// let stats = quick_sort_instrumented(&mut arr);
+    /// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    /// assert!(stats.max_depth <= 10);
+    /// ```
+    pub fn quick_sort_instrumented<T: Ord>(arr: &mut [T]) -> SortStats {
+        quick_sort_instrumented_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using quick sort with a comparator function and returns the operations it
+    /// performed.
+    pub fn quick_sort_instrumented_by<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut stats = SortStats::default();
+        quick_sort_impl(arr, &mut SortOps::new(&mut compare, &mut stats));
+        stats
+    }
+
+    /// Sorts a slice using insertion sort and returns the operations it performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::instrumentation::instrumentation::insertion_sort_instrumented;
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// let stats = insertion_sort_instrumented(&mut arr);
+    /// assert_eq!(stats.comparisons, 3);
+    /// assert_eq!(stats.swaps, 0);
+    /// ```
+    pub fn insertion_sort_instrumented<T: Ord>(arr: &mut [T]) -> SortStats {
+        insertion_sort_instrumented_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using insertion sort with a comparator function and returns the
+    /// operations it performed.
+    pub fn insertion_sort_instrumented_by<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut stats = SortStats::default();
+        insertion_sort_impl(arr, &mut SortOps::new(&mut compare, &mut stats));
+        stats
+    }
+
+    /// Sorts a slice using selection sort and returns the operations it performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::instrumentation::instrumentation::selection_sort_instrumented;
+    ///
+    /// let mut arr = [4, 3, 2, 1];
+    /// let stats = selection_sort_instrumented(&mut arr);
+    /// assert_eq!(stats.comparisons, 6);
+    /// assert_eq!(stats.swaps, 2);
+    /// ```
+    pub fn selection_sort_instrumented<T: Ord>(arr: &mut [T]) -> SortStats {
+        selection_sort_instrumented_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using selection sort with a comparator function and returns the
+    /// operations it performed.
+    pub fn selection_sort_instrumented_by<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut stats = SortStats::default();
+        selection_sort_impl(arr, &mut SortOps::new(&mut compare, &mut stats));
+        stats
+    }
+
+    /// Sorts a slice using bubble sort and returns the operations it performed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::instrumentation::instrumentation::bubble_sort_instrumented;
+    ///
+    /// let mut arr = [4, 3, 2, 1];
+    /// let stats = bubble_sort_instrumented(&mut arr);
+    /// assert_eq!(stats.comparisons, 6);
+    /// assert_eq!(stats.swaps, 6);
+    /// ```
+    pub fn bubble_sort_instrumented<T: Ord>(arr: &mut [T]) -> SortStats {
+        bubble_sort_instrumented_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using bubble sort with a comparator function and returns the operations
+    /// it performed.
+    pub fn bubble_sort_instrumented_by<T, F>(arr: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut stats = SortStats::default();
+        bubble_sort_impl(arr, &mut SortOps::new(&mut compare, &mut stats));
+        stats
+    }
+}
@@ -0,0 +1,359 @@
+//! # Merging
+//!
+//! `merging` combines data that is already sorted: merging sorted slices and iterators, and
+//! set operations on sorted slices.
+
+pub mod merging {
+    use std::cmp::Ordering;
+    use std::fmt;
+
+    use crate::algorithms::sorting::sorting::{heapify, NoObserver, SortOps};
+    use crate::algorithms::timsort::timsort::gallop;
+
+    /// Merges two sorted slices into a new sorted vector.
+    ///
+    /// Equal elements keep their order, with those from `a` before those from `b`. Runs of
+    /// elements that come from the same slice are found by galloping, so merging a short slice
+    /// into a long one takes O(m log(n / m)) comparisons rather than O(n + m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::merge;
+    ///
+    /// assert_eq!(merge(&[1, 4, 6], &[2, 3, 5, 7]), [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    pub fn merge<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        merge_by(a, b, T::cmp)
+    }
+
+    /// Merges two slices sorted according to a comparator function into a new sorted vector.
+    /// See [`merge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::merge_by;
+    ///
+    /// assert_eq!(merge_by(&[6, 4, 1], &[5, 2], |a, b| b.cmp(a)), [6, 5, 4, 2, 1]);
+    /// ```
+    pub fn merge_by<T, F>(a: &[T], b: &[T], mut compare: F) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut merged = Vec::with_capacity(a.len() + b.len());
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            if compare(&a[i], &b[j]) == Ordering::Greater {
+                let n = gallop(&b[j..], false, |x| compare(x, &a[i]) == Ordering::Less);
+                merged.extend_from_slice(&b[j..j + n]);
+                j += n;
+            } else {
+                let n = gallop(&a[i..], false, |x| compare(x, &b[j]) != Ordering::Greater);
+                merged.extend_from_slice(&a[i..i + n]);
+                i += n;
+            }
+        }
+        merged.extend_from_slice(&a[i..]);
+        merged.extend_from_slice(&b[j..]);
+        merged
+    }
+
+    /// Merges two slices sorted according to a key extraction function into a new sorted
+    /// vector. See [`merge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::merge_by_key;
+    ///
+    /// assert_eq!(merge_by_key(&[-1, 3], &[2, -4], |x: &i32| x.abs()), [-1, 2, 3, -4]);
+    /// ```
+    pub fn merge_by_key<T, K, F>(a: &[T], b: &[T], mut f: F) -> Vec<T>
+    where
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        merge_by(a, b, |x, y| f(x).cmp(&f(y)))
+    }
+
+    /// The comparator [`kmerge`] orders items with.
+    pub(crate) type Comparator<T> = fn(&T, &T) -> Ordering;
+
+    /// An iterator over the items of several sorted iterators in sorted order, created by
+    /// [`kmerge`] or [`kmerge_by`].
+    pub struct KMerge<I: Iterator, F> {
+        iters: Vec<I>,
+        /// The next item of every iterator that has not run out, with the iterator's index.
+        heap: Vec<(I::Item, usize)>,
+        compare: F,
+    }
+
+    impl<I, F> fmt::Debug for KMerge<I, F>
+    where
+        I: Iterator + fmt::Debug,
+        I::Item: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("KMerge").field("iters", &self.iters).field("heap", &self.heap).finish()
+        }
+    }
+
+    impl<I, F> KMerge<I, F>
+    where
+        I: Iterator,
+        F: FnMut(&I::Item, &I::Item) -> Ordering,
+    {
+        /// Restores the heap property from `i` down, in a min-heap ordered by item and then by
+        /// iterator index, so that equal items come out in the order of their iterators.
+        fn sift_down(&mut self, i: usize) {
+            let compare = &mut self.compare;
+            let mut reversed = |a: &(I::Item, usize), b: &(I::Item, usize)| compare(&b.0, &a.0).then(b.1.cmp(&a.1));
+            let len = self.heap.len();
+            heapify(&mut self.heap, len, i, &mut SortOps::new(&mut reversed, &mut NoObserver));
+        }
+    }
+
+    impl<I, F> Iterator for KMerge<I, F>
+    where
+        I: Iterator,
+        F: FnMut(&I::Item, &I::Item) -> Ordering,
+    {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            if self.heap.is_empty() {
+                return None;
+            }
+
+            // Take the smallest item and replace it with the next one from the same iterator
+            let index = self.heap[0].1;
+            let item = match self.iters[index].next() {
+                Some(next) => std::mem::replace(&mut self.heap[0], (next, index)).0,
+                None => self.heap.swap_remove(0).0,
+            };
+            self.sift_down(0);
+            Some(item)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.iters.iter().fold((self.heap.len(), Some(self.heap.len())), |(low, high), iter| {
+                let (iter_low, iter_high) = iter.size_hint();
+                (low.saturating_add(iter_low), high.zip(iter_high).and_then(|(a, b)| a.checked_add(b)))
+            })
+        }
+    }
+
+    /// Merges any number of sorted iterators into one sorted iterator.
+    ///
+    /// The next item of every iterator is kept in a binary heap, so each item takes O(log k)
+    /// comparisons for k iterators. Items are pulled from the iterators lazily, and equal items
+    /// come out in the order of the iterators they came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::kmerge;
+    ///
+    /// let shards = vec![vec![1, 4, 7], vec![2, 5], vec![], vec![0, 3, 6, 8]];
+    /// let merged: Vec<i32> = kmerge(shards).collect();
+    /// assert_eq!(merged, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn kmerge<I, T>(iters: I) -> KMerge<<I::Item as IntoIterator>::IntoIter, Comparator<T>>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator<Item = T>,
+        T: Ord,
+    {
+        kmerge_by(iters, T::cmp)
+    }
+
+    /// Merges any number of iterators sorted according to a comparator function into one
+    /// sorted iterator. See [`kmerge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::kmerge_by;
+    ///
+    /// let merged: Vec<u32> = kmerge_by([(0..10).rev(), (5..8).rev()], |a, b| b.cmp(a)).take(4).collect();
+    /// assert_eq!(merged, [9, 8, 7, 7]);
+    /// ```
+    pub fn kmerge_by<I, F>(iters: I, compare: F) -> KMerge<<I::Item as IntoIterator>::IntoIter, F>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        F: FnMut(&<I::Item as IntoIterator>::Item, &<I::Item as IntoIterator>::Item) -> Ordering,
+    {
+        let mut iters: Vec<_> = iters.into_iter().map(IntoIterator::into_iter).collect();
+        let heap = iters
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, iter)| iter.next().map(|item| (item, index)))
+            .collect();
+        let mut kmerge = KMerge { iters, heap, compare };
+        for i in (0..kmerge.heap.len() / 2).rev() {
+            kmerge.sift_down(i);
+        }
+        kmerge
+    }
+
+    /// Returns the union of two sorted slices as a sorted vector.
+    ///
+    /// The slices are treated as multisets: an element that appears `m` times in `a` and `n`
+    /// times in `b` appears `max(m, n)` times in the result, taken from `a` first. Like
+    /// [`merge`], runs from one slice are skipped over by galloping, which makes combining a
+    /// small slice with a large one cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::union;
+    ///
+    /// assert_eq!(union(&[1, 2, 2, 5], &[2, 3, 5, 5]), [1, 2, 2, 3, 5, 5]);
+    /// ```
+    pub fn union<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        union_by(a, b, T::cmp)
+    }
+
+    /// Returns the union of two slices sorted according to a comparator function. See
+    /// [`union`].
+    pub fn union_by<T, F>(a: &[T], b: &[T], compare: F) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        set_operation(a, b, compare, SetOperation { only_a: true, only_b: true, both: true })
+    }
+
+    /// Returns the intersection of two sorted slices as a sorted vector.
+    ///
+    /// An element that appears `m` times in `a` and `n` times in `b` appears `min(m, n)` times
+    /// in the result, taken from `a`. See [`union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::intersection;
+    ///
+    /// assert_eq!(intersection(&[1, 2, 2, 5], &[2, 3, 5, 5]), [2, 5]);
+    /// ```
+    pub fn intersection<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        intersection_by(a, b, T::cmp)
+    }
+
+    /// Returns the intersection of two slices sorted according to a comparator function. See
+    /// [`intersection`].
+    pub fn intersection_by<T, F>(a: &[T], b: &[T], compare: F) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        set_operation(a, b, compare, SetOperation { only_a: false, only_b: false, both: true })
+    }
+
+    /// Returns the elements of sorted slice `a` that are not in sorted slice `b`, as a sorted
+    /// vector.
+    ///
+    /// An element that appears `m` times in `a` and `n` times in `b` appears `m - n` times in
+    /// the result if `m > n`. See [`union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::difference;
+    ///
+    /// assert_eq!(difference(&[1, 2, 2, 5], &[2, 3, 5, 5]), [1, 2]);
+    /// ```
+    pub fn difference<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        difference_by(a, b, T::cmp)
+    }
+
+    /// Returns the elements of `a` that are not in `b`, for slices sorted according to a
+    /// comparator function. See [`difference`].
+    pub fn difference_by<T, F>(a: &[T], b: &[T], compare: F) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        set_operation(a, b, compare, SetOperation { only_a: true, only_b: false, both: false })
+    }
+
+    /// Returns the elements that are in exactly one of two sorted slices, as a sorted vector.
+    ///
+    /// An element that appears `m` times in `a` and `n` times in `b` appears `|m - n|` times in
+    /// the result. See [`union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::merging::merging::symmetric_difference;
+    ///
+    /// assert_eq!(symmetric_difference(&[1, 2, 2, 5], &[2, 3, 5, 5]), [1, 2, 3, 5]);
+    /// ```
+    pub fn symmetric_difference<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        symmetric_difference_by(a, b, T::cmp)
+    }
+
+    /// Returns the elements that are in exactly one of two slices sorted according to a
+    /// comparator function. See [`symmetric_difference`].
+    pub fn symmetric_difference_by<T, F>(a: &[T], b: &[T], compare: F) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        set_operation(a, b, compare, SetOperation { only_a: true, only_b: true, both: false })
+    }
+
+    /// Which elements a set operation keeps: those only in `a`, those only in `b`, and those
+    /// matched in both.
+    struct SetOperation {
+        only_a: bool,
+        only_b: bool,
+        both: bool,
+    }
+
+    fn set_operation<T, F>(a: &[T], b: &[T], mut compare: F, keep: SetOperation) -> Vec<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            match compare(&a[i], &b[j]) {
+                Ordering::Less => {
+                    let n = gallop(&a[i..], false, |x| compare(x, &b[j]) == Ordering::Less);
+                    if keep.only_a {
+                        result.extend_from_slice(&a[i..i + n]);
+                    }
+                    i += n;
+                }
+                Ordering::Greater => {
+                    let n = gallop(&b[j..], false, |x| compare(x, &a[i]) == Ordering::Less);
+                    if keep.only_b {
+                        result.extend_from_slice(&b[j..j + n]);
+                    }
+                    j += n;
+                }
+                Ordering::Equal => {
+                    // Each element of `a` is matched with at most one equal element of `b`
+                    if keep.both {
+                        result.push(a[i].clone());
+                    }
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        if keep.only_a {
+            result.extend_from_slice(&a[i..]);
+        }
+        if keep.only_b {
+            result.extend_from_slice(&b[j..]);
+        }
+        result
+    }
+}
@@ -0,0 +1,421 @@
+//! # Networks
+//!
+//! `networks` sorts slices with sorting networks: fixed sequences of compare-exchange
+//! operations that make the same comparisons whatever the order of the input, so that their
+//! branches are easy to predict and independent comparisons can run side by side.
+
+pub mod networks {
+    use std::cmp::Ordering;
+
+    use crate::algorithms::sorting::sorting::{NoObserver, SortObserver, SortOps};
+
+    /// A compare-exchange of the elements at two positions, which leaves the smaller of them at
+    /// the first position and the larger at the second.
+    pub type Comparator = (usize, usize);
+
+    /// The longest slice there is a fixed network for.
+    pub const MAX_NETWORK_LEN: usize = 16;
+
+    const NETWORK_2: [Comparator; 1] = [
+        (0, 1),
+    ];
+
+    const NETWORK_3: [Comparator; 3] = [
+        (0, 2), (0, 1), (1, 2),
+    ];
+
+    const NETWORK_4: [Comparator; 5] = [
+        (0, 2), (1, 3), (0, 1), (2, 3), (1, 2),
+    ];
+
+    const NETWORK_5: [Comparator; 9] = [
+        (0, 3), (1, 4), (0, 2), (1, 3), (0, 1), (2, 4), (1, 2), (3, 4), (2, 3),
+    ];
+
+    const NETWORK_6: [Comparator; 12] = [
+        (0, 5), (1, 3), (2, 4), (1, 2), (3, 4), (0, 3), (2, 5), (0, 1), (2, 3), (4, 5), (1, 2), (3, 4),
+    ];
+
+    const NETWORK_7: [Comparator; 16] = [
+        (0, 6), (2, 3), (4, 5), (0, 2), (1, 4), (3, 6), (0, 1), (2, 5), (3, 4), (1, 2), (4, 6), (2, 3), (4, 5),
+        (1, 2), (3, 4), (5, 6),
+    ];
+
+    const NETWORK_8: [Comparator; 19] = [
+        (0, 2), (1, 3), (4, 6), (5, 7), (0, 4), (1, 5), (2, 6), (3, 7), (0, 1), (2, 3), (4, 5), (6, 7), (2, 4),
+        (3, 5), (1, 4), (3, 6), (1, 2), (3, 4), (5, 6),
+    ];
+
+    const NETWORK_9: [Comparator; 25] = [
+        (0, 3), (1, 7), (2, 5), (4, 8), (0, 7), (2, 4), (3, 8), (5, 6), (0, 2), (1, 3), (4, 5), (7, 8), (1, 4),
+        (3, 6), (5, 7), (0, 1), (2, 4), (3, 5), (6, 8), (2, 3), (4, 5), (6, 7), (1, 2), (3, 4), (5, 6),
+    ];
+
+    const NETWORK_10: [Comparator; 29] = [
+        (0, 8), (1, 9), (2, 7), (3, 5), (4, 6), (0, 2), (1, 4), (5, 8), (7, 9), (0, 3), (2, 4), (5, 7), (6, 9),
+        (0, 1), (3, 6), (8, 9), (1, 5), (2, 3), (4, 8), (6, 7), (1, 2), (3, 5), (4, 6), (7, 8), (2, 3), (4, 5),
+        (6, 7), (3, 4), (5, 6),
+    ];
+
+    const NETWORK_11: [Comparator; 35] = [
+        (0, 9), (1, 6), (2, 4), (3, 7), (5, 8), (0, 1), (3, 5), (4, 10), (6, 9), (7, 8), (1, 3), (2, 5), (4, 7),
+        (8, 10), (0, 4), (1, 2), (3, 7), (5, 9), (6, 8), (0, 1), (2, 6), (4, 5), (7, 8), (9, 10), (2, 4),
+        (3, 6), (5, 7), (8, 9), (1, 2), (3, 4), (5, 6), (7, 8), (2, 3), (4, 5), (6, 7),
+    ];
+
+    const NETWORK_12: [Comparator; 39] = [
+        (0, 8), (1, 7), (2, 6), (3, 11), (4, 10), (5, 9), (0, 1), (2, 5), (3, 4), (6, 9), (7, 8), (10, 11),
+        (0, 2), (1, 6), (5, 10), (9, 11), (0, 3), (1, 2), (4, 6), (5, 7), (8, 11), (9, 10), (1, 4), (3, 5),
+        (6, 8), (7, 10), (1, 3), (2, 5), (6, 9), (8, 10), (2, 3), (4, 5), (6, 7), (8, 9), (4, 6), (5, 7),
+        (3, 4), (5, 6), (7, 8),
+    ];
+
+    const NETWORK_13: [Comparator; 45] = [
+        (0, 12), (1, 10), (2, 9), (3, 7), (5, 11), (6, 8), (1, 6), (2, 3), (4, 11), (7, 9), (8, 10), (0, 4),
+        (1, 2), (3, 6), (7, 8), (9, 10), (11, 12), (4, 6), (5, 9), (8, 11), (10, 12), (0, 5), (3, 8), (4, 7),
+        (6, 11), (9, 10), (0, 1), (2, 5), (6, 9), (7, 8), (10, 11), (1, 3), (2, 4), (5, 6), (9, 10), (1, 2),
+        (3, 4), (5, 7), (6, 8), (2, 3), (4, 5), (6, 7), (8, 9), (3, 4), (5, 6),
+    ];
+
+    const NETWORK_14: [Comparator; 51] = [
+        (0, 13), (1, 12), (2, 6), (3, 4), (5, 9), (7, 8), (0, 7), (1, 2), (4, 11), (6, 12), (8, 13), (9, 10),
+        (0, 1), (2, 3), (4, 6), (5, 7), (8, 9), (10, 11), (12, 13), (2, 8), (3, 9), (4, 5), (6, 7), (10, 12),
+        (11, 13), (1, 10), (2, 4), (3, 5), (6, 8), (7, 9), (11, 12), (0, 4), (3, 6), (5, 8), (7, 11), (9, 12),
+        (0, 2), (1, 4), (7, 10), (9, 11), (1, 3), (4, 6), (5, 7), (8, 10), (1, 2), (3, 4), (5, 6), (7, 8),
+        (9, 10), (4, 5), (6, 7),
+    ];
+
+    const NETWORK_15: [Comparator; 56] = [
+        (0, 11), (1, 14), (2, 13), (3, 7), (4, 5), (6, 10), (8, 9), (0, 6), (1, 8), (2, 3), (5, 12), (7, 13),
+        (9, 14), (10, 11), (1, 2), (3, 4), (5, 7), (6, 8), (9, 10), (11, 12), (13, 14), (0, 2), (3, 9), (4, 10),
+        (5, 6), (7, 8), (11, 13), (12, 14), (0, 1), (2, 11), (3, 5), (4, 6), (7, 9), (8, 10), (12, 13), (0, 3),
+        (1, 5), (4, 7), (6, 9), (8, 12), (10, 13), (1, 3), (2, 5), (8, 11), (10, 12), (2, 4), (5, 7), (6, 8),
+        (9, 11), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (5, 6), (7, 8),
+    ];
+
+    const NETWORK_16: [Comparator; 60] = [
+        (0, 13), (1, 12), (2, 15), (3, 14), (4, 8), (5, 6), (7, 11), (9, 10), (0, 5), (1, 7), (2, 9), (3, 4),
+        (6, 13), (8, 14), (10, 15), (11, 12), (0, 1), (2, 3), (4, 5), (6, 8), (7, 9), (10, 11), (12, 13),
+        (14, 15), (0, 2), (1, 3), (4, 10), (5, 11), (6, 7), (8, 9), (12, 14), (13, 15), (1, 2), (3, 12), (4, 6),
+        (5, 7), (8, 10), (9, 11), (13, 14), (1, 4), (2, 6), (5, 8), (7, 10), (9, 13), (11, 14), (2, 4), (3, 6),
+        (9, 12), (11, 13), (3, 5), (6, 8), (7, 9), (10, 12), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12), (6, 7),
+        (8, 9),
+    ];
+
+    /// The network with the fewest comparators known for each length, indexed by length.
+    const NETWORKS: [&[Comparator]; MAX_NETWORK_LEN + 1] = [
+        &[], &[], &NETWORK_2, &NETWORK_3, &NETWORK_4, &NETWORK_5, &NETWORK_6, &NETWORK_7, &NETWORK_8, &NETWORK_9,
+        &NETWORK_10, &NETWORK_11, &NETWORK_12, &NETWORK_13, &NETWORK_14, &NETWORK_15, &NETWORK_16,
+    ];
+
+    /// Returns the sorting network with the fewest comparators known for slices of length
+    /// `len`, or `None` if `len` is more than [`MAX_NETWORK_LEN`].
+    ///
+    /// The networks for up to 12 elements are proven to be as small as possible. Those for 13
+    /// to 16 elements are the smallest known: Green's 60-comparator network for 16 elements,
+    /// with wires pruned from it for 14 and 15, and a 45-comparator network for 13.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::sorting_network;
+    ///
+    /// assert_eq!(sorting_network(3), Some(&[(0, 2), (0, 1), (1, 2)][..]));
+    /// assert_eq!(sorting_network(16).map(|network| network.len()), Some(60));
+    /// assert_eq!(sorting_network(17), None);
+    /// ```
+    pub fn sorting_network(len: usize) -> Option<&'static [Comparator]> {
+        NETWORKS.get(len).copied()
+    }
+
+    /// Sorts a slice of at most [`MAX_NETWORK_LEN`] elements with its sorting network.
+    ///
+    /// See [`sorting_network`] for the networks used. The sort is not stable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is longer than [`MAX_NETWORK_LEN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::network_sort;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// network_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn network_sort<T: Ord>(arr: &mut [T]) {
+        network_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice of at most [`MAX_NETWORK_LEN`] elements with its sorting network and a
+    /// comparator function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is longer than [`MAX_NETWORK_LEN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::network_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// network_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn network_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        network_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice of at most [`MAX_NETWORK_LEN`] elements with its sorting network and a
+    /// key extraction function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is longer than [`MAX_NETWORK_LEN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::network_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// network_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn network_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        network_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn network_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let network = sorting_network(arr.len())
+            .unwrap_or_else(|| panic!("no sorting network for {} elements", arr.len()));
+        for &(i, j) in network {
+            compare_exchange(arr, i, j, ops);
+        }
+    }
+
+    /// Applies the comparators of a network to a slice, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a comparator refers to a position outside the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::apply_network;
+    ///
+    /// // Only sorts the first two elements and the last two
+    /// let mut arr = [2, 1, 4, 3];
+    /// apply_network(&mut arr, &[(0, 1), (2, 3)]);
+    /// assert_eq!(arr, [1, 2, 3, 4]);
+    /// ```
+    pub fn apply_network<T: Ord>(arr: &mut [T], network: &[Comparator]) {
+        apply_network_by(arr, network, T::cmp);
+    }
+
+    /// Applies the comparators of a network to a slice, in order, with a comparator function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a comparator refers to a position outside the slice.
+    pub fn apply_network_by<T, F>(arr: &mut [T], network: &[Comparator], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut compare, &mut observer);
+        for &(i, j) in network {
+            compare_exchange(arr, i, j, &mut ops);
+        }
+    }
+
+    /// Returns the comparators of Batcher's bitonic sorting network for slices of length `len`.
+    ///
+    /// The network has (log n)(log n + 1) / 2 layers of n / 2 comparators each. Comparators
+    /// that sort a run in descending order have their first position after their second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is neither zero nor a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::bitonic_network;
+    ///
+    /// assert_eq!(bitonic_network(4), [(0, 1), (3, 2), (0, 2), (1, 3), (0, 1), (2, 3)]);
+    /// ```
+    pub fn bitonic_network(len: usize) -> Vec<Comparator> {
+        bitonic_comparators(len).collect()
+    }
+
+    /// Sorts a slice whose length is a power of two using bitonic sort.
+    ///
+    /// Bitonic sort is Batcher's sorting network: it sorts pairs in alternating directions,
+    /// so that neighbouring pairs form bitonic sequences that rise and then fall, merges those
+    /// into alternating runs of four, and so on until one run is left. It always makes
+    /// (n / 4)(log n)(log n + 1) comparisons, O(n log^2 n), whatever the input, and sorts in
+    /// place. The sort is not stable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice is neither zero nor a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::bitonic_sort;
+    ///
+    /// let mut arr = [8, 3, 5, 1, 7, 2, 6, 4];
+    /// bitonic_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn bitonic_sort<T: Ord>(arr: &mut [T]) {
+        bitonic_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice whose length is a power of two using bitonic sort with a comparator
+    /// function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice is neither zero nor a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::bitonic_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// bitonic_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [4, 3, 2, 1]);
+    /// ```
+    pub fn bitonic_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        bitonic_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice whose length is a power of two using bitonic sort with a key extraction
+    /// function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice is neither zero nor a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::bitonic_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2, 0];
+    /// bitonic_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [0, 1, -2, -3]);
+    /// ```
+    pub fn bitonic_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        bitonic_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn bitonic_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        for (i, j) in bitonic_comparators(arr.len()) {
+            compare_exchange(arr, i, j, ops);
+        }
+    }
+
+    /// Yields the comparators of the bitonic network for `len` elements one at a time, so that
+    /// sorting does not need to allocate them.
+    fn bitonic_comparators(len: usize) -> impl Iterator<Item = Comparator> {
+        assert!(len == 0 || len.is_power_of_two(), "bitonic sort needs a power of two length, not {}", len);
+        let log_len = len.checked_ilog2().unwrap_or(0);
+
+        // Stage `block` merges bitonic blocks of that size, comparing elements `stride` apart,
+        // into runs that rise where bit `block` of the position is clear and fall where it is set
+        let steps = (1..=log_len).flat_map(|s| (0..s).rev().map(move |t| (1 << s, 1 << t)));
+        steps.flat_map(move |(block, stride)| {
+            (0..len).filter(move |i| i & stride == 0).map(move |i| {
+                if i & block == 0 {
+                    (i, i + stride)
+                } else {
+                    (i + stride, i)
+                }
+            })
+        })
+    }
+
+    /// Checks whether a network sorts every slice of length `len`, by the 0-1 principle.
+    ///
+    /// A network sorts every input if and only if it sorts every input made of zeros and ones,
+    /// so only those 2^len inputs are tried, each packed into the bits of an integer. Returns
+    /// the first input of zeros and ones the network fails to sort.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 64 or more, or if a comparator refers to a position of `len` or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::networks::networks::{sorting_network, verify_network};
+    ///
+    /// assert_eq!(verify_network(8, sorting_network(8).unwrap()), Ok(()));
+    ///
+    /// // Two comparators cannot sort three elements
+    /// assert_eq!(verify_network(3, &[(0, 1), (1, 2)]), Err(vec![1, 1, 0]));
+    /// ```
+    pub fn verify_network(len: usize, network: &[Comparator]) -> Result<(), Vec<u8>> {
+        assert!(len < 64, "cannot check all 2^{} inputs", len);
+        assert!(network.iter().all(|&(i, j)| i < len && j < len), "comparator out of range for {} elements", len);
+
+        let all = (1u64 << len) - 1;
+        for input in 0..=all {
+            let mut bits = input;
+            for &(i, j) in network {
+                // A one at `i` and a zero at `j` are out of order
+                if bits >> i & 1 == 1 && bits >> j & 1 == 0 {
+                    bits ^= 1 << i | 1 << j;
+                }
+            }
+            // Sorted means every zero comes before every one
+            let ones = bits.count_ones();
+            if bits != all & !((1u64 << (len as u32 - ones)) - 1) {
+                return Err((0..len).map(|i| (input >> i & 1) as u8).collect());
+            }
+        }
+        Ok(())
+    }
+
+    /// Leaves the smaller of `arr[i]` and `arr[j]` at `i` and the larger at `j`.
+    fn compare_exchange<T, F, O>(arr: &mut [T], i: usize, j: usize, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        if ops.compare(arr, i, j) == Ordering::Greater {
+            ops.swap(arr, i, j);
+        }
+    }
+}
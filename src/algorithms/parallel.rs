@@ -0,0 +1,198 @@
+//! # Parallel
+//!
+//! `parallel` contains multi-threaded versions of the sorting algorithms, built on scoped
+//! threads from the standard library.
+
+pub mod parallel {
+    use std::cmp::Ordering;
+    use std::thread;
+
+    use crate::algorithms::sorting::sorting::{
+        choose_pivot, merge_runs, merge_sort_by, partition_3way, quick_sort_by, NoObserver, SortOps,
+    };
+
+    /// Controls how a parallel sort divides its work.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ParallelConfig {
+        /// The maximum number of threads working on the slice at the same time.
+        pub threads: usize,
+        /// Subslices of at most this length are sorted sequentially on a single thread.
+        pub sequential_cutoff: usize,
+    }
+
+    impl ParallelConfig {
+        /// Creates a configuration with the given thread count and sequential cutoff.
+        ///
+        /// A thread count of zero is treated as one.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use algorithm_playground::algorithms::parallel::parallel::ParallelConfig;
+        ///
+        /// let config = ParallelConfig::new(4, 10_000);
+        /// assert_eq!(config.threads, 4);
+        /// ```
+        pub fn new(threads: usize, sequential_cutoff: usize) -> Self {
+            ParallelConfig {
+                threads: threads.max(1),
+                sequential_cutoff,
+            }
+        }
+    }
+
+    impl Default for ParallelConfig {
+        /// Uses every core the operating system reports, and a sequential cutoff of 8192
+        /// elements.
+        fn default() -> Self {
+            let threads = thread::available_parallelism().map_or(1, |n| n.get());
+            ParallelConfig::new(threads, 8192)
+        }
+    }
+
+    /// Sorts a slice using merge sort spread over several threads.
+    ///
+    /// The slice is split in half recursively, with each half sorted on its own scoped thread,
+    /// until the thread budget is used up or a half is no longer than the sequential cutoff.
+    /// Those pieces are sorted with [`merge_sort_by`] and the halves are then merged back
+    /// together. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::parallel::parallel::{par_merge_sort, ParallelConfig};
+    ///
+    /// let mut arr: Vec<u32> = (0..10_000).rev().collect();
+    /// par_merge_sort(&mut arr, &ParallelConfig::new(4, 1000));
+    /// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn par_merge_sort<T: Ord + Send>(arr: &mut [T], config: &ParallelConfig) {
+        par_merge_sort_by(arr, config, T::cmp);
+    }
+
+    /// Sorts a slice using parallel merge sort with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::parallel::parallel::{par_merge_sort_by, ParallelConfig};
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// par_merge_sort_by(&mut arr, &ParallelConfig::default(), |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn par_merge_sort_by<T, F>(arr: &mut [T], config: &ParallelConfig, compare: F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        par_merge_sort_recursive(arr, config.threads.max(1), config.sequential_cutoff, &compare);
+    }
+
+    fn par_merge_sort_recursive<T, F>(arr: &mut [T], threads: usize, cutoff: usize, compare: &F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let len = arr.len();
+        if threads <= 1 || len <= cutoff.max(1) {
+            merge_sort_by(arr, compare);
+            return;
+        }
+
+        // Sort the right half on a new thread while this thread sorts the left half
+        let mid = len / 2;
+        let left_threads = threads / 2;
+        let (left, right) = arr.split_at_mut(mid);
+        thread::scope(|scope| {
+            scope.spawn(|| par_merge_sort_recursive(right, threads - left_threads, cutoff, compare));
+            par_merge_sort_recursive(left, left_threads, cutoff, compare);
+        });
+
+        let mut buf = Vec::with_capacity(len / 2);
+        merge_runs(arr, mid, &mut buf, &mut SortOps::new(&mut &*compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using quick sort spread over several threads.
+    ///
+    /// Each step partitions the slice three ways around a median-of-three (or ninther) pivot,
+    /// sets aside the elements equal to it, and hands the two sides to separate scoped threads,
+    /// until the thread budget is used up or a side is no longer than the sequential cutoff.
+    /// The threads are shared between the sides in proportion to their lengths, so skewed
+    /// partitions and runs of duplicates do not leave threads idle. Those pieces are sorted
+    /// with [`quick_sort_by`], which keeps the worst case at O(n log n). The sort is not
+    /// stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::parallel::parallel::{par_quick_sort, ParallelConfig};
+    ///
+    /// let mut arr: Vec<u32> = (0..10_000).rev().collect();
+    /// par_quick_sort(&mut arr, &ParallelConfig::new(4, 1000));
+    /// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn par_quick_sort<T: Ord + Send>(arr: &mut [T], config: &ParallelConfig) {
+        par_quick_sort_by(arr, config, T::cmp);
+    }
+
+    /// Sorts a slice using parallel quick sort with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::parallel::parallel::{par_quick_sort_by, ParallelConfig};
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// par_quick_sort_by(&mut arr, &ParallelConfig::default(), |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn par_quick_sort_by<T, F>(arr: &mut [T], config: &ParallelConfig, compare: F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        par_quick_sort_recursive(arr, config.threads.max(1), config.sequential_cutoff, &compare);
+    }
+
+    fn par_quick_sort_recursive<T, F>(arr: &mut [T], threads: usize, cutoff: usize, compare: &F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let len = arr.len();
+        if threads <= 1 || len <= cutoff.max(1) {
+            quick_sort_by(arr, compare);
+            return;
+        }
+
+        let mut compare = compare;
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut compare, &mut observer);
+        let pivot_index = choose_pivot(arr, &mut ops);
+        let (equal_start, equal_end) = partition_3way(arr, pivot_index, &mut ops);
+        let (left, rest) = arr.split_at_mut(equal_start);
+        let right = &mut rest[equal_end - equal_start..];
+
+        // Share the threads in proportion to the length of each side, rounding to the nearest
+        let total = left.len() + right.len();
+        if total == 0 {
+            return;
+        }
+        let left_threads = (threads * left.len() + total / 2) / total;
+        let right_threads = threads - left_threads;
+        if left_threads == 0 || right_threads == 0 {
+            // One side is too short to be worth a thread, so sort it here and give every
+            // thread to the other
+            par_quick_sort_recursive(left, left_threads, cutoff, compare);
+            par_quick_sort_recursive(right, right_threads, cutoff, compare);
+            return;
+        }
+
+        // Sort the right side on a new thread while this thread sorts the left side
+        thread::scope(|scope| {
+            scope.spawn(|| par_quick_sort_recursive(right, right_threads, cutoff, compare));
+            par_quick_sort_recursive(left, left_threads, cutoff, compare);
+        });
+    }
+}
@@ -0,0 +1,301 @@
+//! # Radix
+//!
+//! `radix` contains non-comparison sorts for integer keys: LSD and MSD radix sort and counting
+//! sort.
+
+pub mod radix {
+    use crate::algorithms::sorting::sorting::insertion_sort_by_key;
+
+    /// Buckets of at most this many elements are finished with insertion sort by MSD radix sort.
+    const MSD_INSERTION_SORT_THRESHOLD: usize = 32;
+
+    /// A fixed-width key that can be sorted one byte at a time.
+    ///
+    /// `to_radix` maps the key to an unsigned integer that sorts in the same order as the key
+    /// itself. Unsigned integers map to themselves; signed integers are biased by flipping the
+    /// sign bit so that negative values come before positive ones.
+    pub trait RadixKey: Copy {
+        /// The number of significant bytes in the value returned by `to_radix`.
+        const BYTES: usize;
+
+        /// Maps the key to an unsigned integer with the same ordering.
+        fn to_radix(self) -> u128;
+
+        /// Returns the `byte`-th least significant byte of `to_radix`.
+        fn radix_byte(self, byte: usize) -> u8 {
+            (self.to_radix() >> (8 * byte)) as u8
+        }
+    }
+
+    macro_rules! impl_radix_key_unsigned {
+        ($($t:ty),*) => {$(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                fn to_radix(self) -> u128 {
+                    self as u128
+                }
+
+                fn radix_byte(self, byte: usize) -> u8 {
+                    (self >> (8 * byte)) as u8
+                }
+            }
+        )*};
+    }
+
+    macro_rules! impl_radix_key_signed {
+        ($($t:ty => $u:ty),*) => {$(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                fn to_radix(self) -> u128 {
+                    ((self as $u) ^ (1 << (<$u>::BITS - 1))) as u128
+                }
+
+                fn radix_byte(self, byte: usize) -> u8 {
+                    (((self as $u) ^ (1 << (<$u>::BITS - 1))) >> (8 * byte)) as u8
+                }
+            }
+        )*};
+    }
+
+    impl_radix_key_unsigned!(u8, u16, u32, u64, u128, usize);
+    impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+    /// Sorts a slice of integers using least significant digit (LSD) radix sort.
+    ///
+    /// Keys are distributed one byte at a time, from the least to the most significant, into a
+    /// scratch buffer of the same length. Passes where every key has the same byte are skipped.
+    /// Runs in O(n * k) time for k-byte keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::radix::radix::radix_sort;
+    ///
+    /// let mut arr = [170i64, -45, 75, -90, 802, 24, 2, 66];
+    /// radix_sort(&mut arr);
+    /// assert_eq!(arr, [-90, -45, 2, 24, 66, 75, 170, 802]);
+    /// ```
+    pub fn radix_sort<K: RadixKey>(arr: &mut [K]) {
+        let len = arr.len();
+        if len <= 1 {
+            return;
+        }
+
+        let mut buf = arr.to_vec();
+        for byte in 0..K::BYTES {
+            let counts = byte_histogram(arr.iter().map(|&key| key.radix_byte(byte)));
+            if counts.contains(&len) {
+                continue;
+            }
+
+            let mut offsets = prefix_sums(&counts);
+            for &key in arr.iter() {
+                let digit = key.radix_byte(byte) as usize;
+                buf[offsets[digit]] = key;
+                offsets[digit] += 1;
+            }
+            arr.copy_from_slice(&buf);
+        }
+    }
+
+    /// Sorts a slice of records by an integer key using LSD radix sort.
+    ///
+    /// The key of every element is extracted exactly once. The sort is stable: records with
+    /// equal keys keep their original relative order. Elements are only ever swapped, so they
+    /// do not need to be `Clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::radix::radix::radix_sort_by_key;
+    ///
+    /// let mut arr = [("c", 3u32), ("a", 1), ("b", 2), ("a2", 1)];
+    /// radix_sort_by_key(&mut arr, |&(_, id)| id);
+    /// assert_eq!(arr, [("a", 1), ("a2", 1), ("b", 2), ("c", 3)]);
+    /// ```
+    pub fn radix_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: RadixKey,
+        F: FnMut(&T) -> K,
+    {
+        let len = arr.len();
+        if len <= 1 {
+            return;
+        }
+
+        // Radix sort the positions of the elements, then move the elements into place
+        let keys: Vec<K> = arr.iter().map(&mut f).collect();
+        let mut order: Vec<usize> = (0..len).collect();
+        let mut buf = vec![0; len];
+        for byte in 0..K::BYTES {
+            let counts = byte_histogram(keys.iter().map(|key| key.radix_byte(byte)));
+            if counts.contains(&len) {
+                continue;
+            }
+
+            let mut offsets = prefix_sums(&counts);
+            for &index in order.iter() {
+                let digit = keys[index].radix_byte(byte) as usize;
+                buf[offsets[digit]] = index;
+                offsets[digit] += 1;
+            }
+            std::mem::swap(&mut order, &mut buf);
+        }
+
+        apply_permutation(arr, &mut order);
+    }
+
+    /// Sorts a slice of integers using most significant digit (MSD) radix sort.
+    ///
+    /// This is an in-place "American flag" sort: each pass permutes the slice into 256 buckets
+    /// by one byte, starting from the most significant, and then recurses into every bucket
+    /// with the next byte. Buckets of up to 32 elements are finished with insertion sort. Unlike
+    /// [`radix_sort`] it needs no scratch buffer, but it is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::radix::radix::msd_radix_sort;
+    ///
+    /// let mut arr = [170u32, 45, 75, 90, 802, 24, 2, 66];
+    /// msd_radix_sort(&mut arr);
+    /// assert_eq!(arr, [2, 24, 45, 66, 75, 90, 170, 802]);
+    /// ```
+    pub fn msd_radix_sort<K: RadixKey>(arr: &mut [K]) {
+        if K::BYTES > 0 {
+            msd_radix_sort_recursive(arr, K::BYTES - 1);
+        }
+    }
+
+    fn msd_radix_sort_recursive<K: RadixKey>(arr: &mut [K], byte: usize) {
+        let len = arr.len();
+        if len <= MSD_INSERTION_SORT_THRESHOLD {
+            insertion_sort_by_key(arr, |key| key.to_radix());
+            return;
+        }
+
+        let counts = byte_histogram(arr.iter().map(|key| key.radix_byte(byte)));
+        let starts = prefix_sums(&counts);
+
+        // Swap every element into its bucket, filling the buckets from the front
+        let mut next = starts;
+        for digit in 0..256 {
+            let end = starts[digit] + counts[digit];
+            while next[digit] < end {
+                let target = arr[next[digit]].radix_byte(byte) as usize;
+                if target == digit {
+                    next[digit] += 1;
+                } else {
+                    arr.swap(next[digit], next[target]);
+                    next[target] += 1;
+                }
+            }
+        }
+
+        if byte > 0 {
+            for digit in 0..256 {
+                let start = starts[digit];
+                msd_radix_sort_recursive(&mut arr[start..start + counts[digit]], byte - 1);
+            }
+        }
+    }
+
+    /// Sorts a slice of integers using counting sort.
+    ///
+    /// Counts how often every value between the minimum and maximum of the slice occurs, then
+    /// writes the values back in order. Runs in O(n + r) time and memory, where r is the
+    /// difference between the largest and smallest key, so it is only a good choice when keys
+    /// fall into a small range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range of keys is too large to allocate a count for every value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::radix::radix::counting_sort;
+    ///
+    /// let mut arr = [3i8, -1, 2, -1, 0, 3];
+    /// counting_sort(&mut arr);
+    /// assert_eq!(arr, [-1, -1, 0, 2, 3, 3]);
+    /// ```
+    pub fn counting_sort<K: RadixKey>(arr: &mut [K]) {
+        let len = arr.len();
+        if len <= 1 {
+            return;
+        }
+
+        let min = arr.iter().map(|key| key.to_radix()).min().unwrap();
+        let max = arr.iter().map(|key| key.to_radix()).max().unwrap();
+        let range = usize::try_from(max - min)
+            .ok()
+            .and_then(|r| r.checked_add(1))
+            .expect("key range is too large for counting sort");
+
+        // Reserve fallibly, since `vec!` would abort the process on a range too large to allocate
+        let mut counts = Vec::new();
+        if counts.try_reserve_exact(range).is_err() {
+            panic!("key range is too large for counting sort");
+        }
+        counts.resize(range, 0usize);
+        for key in arr.iter() {
+            counts[(key.to_radix() - min) as usize] += 1;
+        }
+
+        let mut offsets = counts;
+        let mut total = 0;
+        for count in offsets.iter_mut() {
+            let start = total;
+            total += *count;
+            *count = start;
+        }
+
+        let buf = arr.to_vec();
+        for &key in buf.iter() {
+            let slot = &mut offsets[(key.to_radix() - min) as usize];
+            arr[*slot] = key;
+            *slot += 1;
+        }
+    }
+
+    /// Counts how many times each byte value occurs.
+    fn byte_histogram(bytes: impl Iterator<Item = u8>) -> [usize; 256] {
+        let mut counts = [0; 256];
+        for byte in bytes {
+            counts[byte as usize] += 1;
+        }
+        counts
+    }
+
+    /// Returns the index where each bucket starts, given the size of every bucket.
+    fn prefix_sums(counts: &[usize; 256]) -> [usize; 256] {
+        let mut starts = [0; 256];
+        let mut total = 0;
+        for (start, &count) in starts.iter_mut().zip(counts.iter()) {
+            *start = total;
+            total += count;
+        }
+        starts
+    }
+
+    /// Reorders `arr` so that position `i` holds the element that was at `order[i]`, using only
+    /// swaps. `order` is left as the identity permutation.
+    fn apply_permutation<T>(arr: &mut [T], order: &mut [usize]) {
+        for start in 0..arr.len() {
+            // Follow the cycle through `start`, pulling each element into place
+            let mut current = start;
+            loop {
+                let next = order[current];
+                order[current] = current;
+                if next == start {
+                    break;
+                }
+                arr.swap(current, next);
+                current = next;
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 /// This module contains implementations of searching algorithms.
 pub mod searching {
+    use std::cmp::Ordering;
     use std::collections::{HashSet, VecDeque};
+    use std::ops::Range;
 
     /// Performs a linear search on a slice to find a target element.
     ///
@@ -26,7 +28,6 @@ pub mod searching {
     /// let arr = vec![1, 2, 3, 4, 5];
     /// assert_eq!(linear_search(&arr, &3), Some(2));
     /// ```
-    
     pub fn linear_search<T: PartialEq>(arr: &[T], target: &T) -> Option<usize> {
         for (index, item) in arr.iter().enumerate() {
             if item == target {
@@ -36,11 +37,282 @@ pub mod searching {
         None
     }
 
+    /// Performs a linear search on a slice to find the first element that satisfies a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A slice of elements to search through.
+    /// * `pred` - The predicate to test each element with.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first element for which `pred` returns `true`, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::find_by;
+    ///
+    /// let arr = [3, 8, 5, 12];
+    /// assert_eq!(find_by(&arr, |&x| x % 4 == 0), Some(1));
+    /// assert_eq!(find_by(&arr, |&x| x > 20), None);
+    /// ```
+    pub fn find_by<T, P>(arr: &[T], pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        arr.iter().position(pred)
+    }
+
+    /// Performs a linear search from the end of a slice to find the last element equal to the
+    /// target.
+    ///
+    /// # Returns
+    ///
+    /// The index of the last element equal to the target, or `None` if the target is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::rfind;
+    ///
+    /// let arr = ['a', 'b', 'a', 'c'];
+    /// assert_eq!(rfind(&arr, &'a'), Some(2));
+    /// assert_eq!(rfind(&arr, &'z'), None);
+    /// ```
+    pub fn rfind<T: PartialEq>(arr: &[T], target: &T) -> Option<usize> {
+        rfind_by(arr, |item| item == target)
+    }
+
+    /// Performs a linear search from the end of a slice to find the last element that
+    /// satisfies a predicate. See [`rfind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::rfind_by;
+    ///
+    /// let log = ["start", "error: disk", "retry", "error: net", "done"];
+    /// assert_eq!(rfind_by(&log, |line| line.starts_with("error")), Some(3));
+    /// ```
+    pub fn rfind_by<T, P>(arr: &[T], pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        arr.iter().rposition(pred)
+    }
+
+    /// Finds every element of a slice equal to the target.
+    ///
+    /// The search is lazy: each call to `next` scans on from the previous match, so taking only
+    /// the first few matches does not scan the whole slice.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the indices of the matching elements, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::find_all;
+    ///
+    /// let arr = [1, 0, 1, 1, 0];
+    /// assert!(find_all(&arr, &1).eq([0, 2, 3]));
+    /// assert_eq!(find_all(&arr, &0).next_back(), Some(4));
+    /// ```
+    pub fn find_all<'a, T: PartialEq>(arr: &'a [T], target: &'a T) -> impl DoubleEndedIterator<Item = usize> + 'a {
+        find_all_by(arr, move |item| item == target)
+    }
+
+    /// Finds every element of a slice that satisfies a predicate. See [`find_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::find_all_by;
+    ///
+    /// let words = ["Rust", "is", "Fast", "and", "Safe"];
+    /// let capitalized: Vec<usize> = find_all_by(&words, |w| w.starts_with(char::is_uppercase)).collect();
+    /// assert_eq!(capitalized, [0, 2, 4]);
+    /// ```
+    pub fn find_all_by<'a, T, P>(arr: &'a [T], mut pred: P) -> impl DoubleEndedIterator<Item = usize> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        arr.iter().enumerate().filter_map(move |(index, item)| pred(item).then_some(index))
+    }
+
+    /// Counts the elements of a slice equal to the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::count_matches;
+    ///
+    /// assert_eq!(count_matches(&[2, 7, 2, 2, 9], &2), 3);
+    /// assert_eq!(count_matches(&[2, 7], &4), 0);
+    /// ```
+    pub fn count_matches<T: PartialEq>(arr: &[T], target: &T) -> usize {
+        count_matches_by(arr, |item| item == target)
+    }
+
+    /// Counts the elements of a slice that satisfy a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::count_matches_by;
+    ///
+    /// assert_eq!(count_matches_by(&[-3, 4, -1, 0], |&x| x < 0), 2);
+    /// ```
+    pub fn count_matches_by<T, P>(arr: &[T], mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        arr.iter().filter(|item| pred(item)).count()
+    }
+
+    /// Performs a linear search that uses the last element of the slice as a sentinel.
+    ///
+    /// The target is written over the last element for the duration of the search, so the scan
+    /// is guaranteed to stop there and the loop needs no separate end-of-slice test. The last
+    /// element is restored before returning, even if a comparison panics, which is why the
+    /// slice must be mutable.
+    ///
+    /// This shows the classic technique rather than beating [`linear_search`]: indexing is still
+    /// bounds checked on every step, since a `PartialEq` implementation cannot be trusted to
+    /// match the sentinel. A target that is not equal to itself, such as a NaN, could never
+    /// match it, so it is searched for with [`linear_search`] instead. For a faster scan of
+    /// primitives, see [`linear_search_chunked`].
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A slice of elements to search through.
+    /// * `target` - The target element to search for.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`linear_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::linear_search_sentinel;
+    ///
+    /// let mut arr = [4, 8, 15, 16, 23, 42];
+    /// assert_eq!(linear_search_sentinel(&mut arr, &16), Some(3));
+    /// assert_eq!(linear_search_sentinel(&mut arr, &7), None);
+    /// assert_eq!(arr, [4, 8, 15, 16, 23, 42]);
+    /// ```
+    pub fn linear_search_sentinel<T: Copy + PartialEq>(arr: &mut [T], target: &T) -> Option<usize> {
+        let last_index = arr.len().checked_sub(1)?;
+        // Deliberately compares the target to itself, which is false for NaNs
+        #[allow(clippy::eq_op)]
+        if *target != *target {
+            return linear_search(arr, target);
+        }
+        let last = arr[last_index];
+        if last == *target {
+            return linear_search(&arr[..last_index], target).or(Some(last_index));
+        }
+
+        let sentinel = Sentinel { arr, last };
+        sentinel.arr[last_index] = *target;
+        let mut index = 0;
+        while sentinel.arr[index] != *target {
+            index += 1;
+        }
+        (index < last_index).then_some(index)
+    }
+
+    /// Puts back the element a sentinel search overwrote when dropped.
+    struct Sentinel<'a, T: Copy> {
+        arr: &'a mut [T],
+        last: T,
+    }
+
+    impl<T: Copy> Drop for Sentinel<'_, T> {
+        fn drop(&mut self) {
+            if let Some(slot) = self.arr.last_mut() {
+                *slot = self.last;
+            }
+        }
+    }
+
+    /// The number of elements [`linear_search_chunked`] compares at a time.
+    const SEARCH_CHUNK: usize = 16;
+
+    /// Performs a linear search on a slice of primitives a chunk of elements at a time.
+    ///
+    /// Each chunk of 16 elements is compared to the target without stopping at the first
+    /// match, which lets the compiler compare them with SIMD instructions. Only a chunk known
+    /// to hold a match is scanned again to find where, which can make it several times faster
+    /// than [`linear_search`] on long slices of integers.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`linear_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::linear_search_chunked;
+    ///
+    /// let arr: Vec<u8> = (0..=255).collect();
+    /// assert_eq!(linear_search_chunked(&arr, &200), Some(200));
+    /// assert_eq!(linear_search_chunked(&arr[..100], &200), None);
+    /// ```
+    pub fn linear_search_chunked<T: Copy + PartialEq>(arr: &[T], target: &T) -> Option<usize> {
+        let target = *target;
+        let mut chunks = arr.chunks_exact(SEARCH_CHUNK);
+        for (chunk_index, chunk) in chunks.by_ref().enumerate() {
+            if chunk.iter().fold(false, |found, &item| found | (item == target)) {
+                return linear_search(chunk, &target).map(|index| chunk_index * SEARCH_CHUNK + index);
+            }
+        }
+        let offset = arr.len() - chunks.remainder().len();
+        linear_search(chunks.remainder(), &target).map(|index| offset + index)
+    }
+
+    /// Performs a linear search on anything that can be iterated over, such as a linked list,
+    /// a set or a stream of values read one at a time.
+    ///
+    /// The search stops at the first match, so the rest of the iterator is never consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The elements to search through.
+    /// * `target` - The target element to search for, compared to the items by `PartialEq`.
+    ///
+    /// # Returns
+    ///
+    /// The position of the first matching item, counting from zero, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::LinkedList;
+    /// use algorithm_playground::algorithms::searching::searching::linear_search_iter;
+    ///
+    /// let list = LinkedList::from(["north", "east", "south"]);
+    /// assert_eq!(linear_search_iter(&list, &&"east"), Some(1));
+    /// assert_eq!(linear_search_iter("hello".chars(), &'l'), Some(2));
+    /// assert_eq!(linear_search_iter(1.., &1000), Some(999));
+    /// ```
+    pub fn linear_search_iter<I, U>(items: I, target: &U) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: PartialEq<U>,
+    {
+        items.into_iter().position(|item| item == *target)
+    }
+
     /// Performs a binary search on a sorted slice to find a target element.
     ///
     /// This function assumes that the slice is sorted in ascending order and uses the `Ord` trait
-    /// to compare elements. It repeatedly divides the search space in half until the target element
-    /// is found or the search space is exhausted.
+    /// to compare elements. It repeatedly halves the range of indices the target could be at
+    /// until the range is empty, so it makes at most about log2(n) + 1 comparisons and never
+    /// panics, even on an empty slice.
     ///
     /// # Arguments
     ///
@@ -49,34 +321,1058 @@ pub mod searching {
     ///
     /// # Returns
     ///
-    /// An `Option` containing the index of the target element if found, or `None` if the target
-    /// element is not present in the slice.
+    /// `Ok` with the index of the first element equal to the target, or `Err` with the index the
+    /// target could be inserted at to keep the slice sorted if it is not present.
     ///
     /// # Examples
     ///
     /// ```
     /// use algorithm_playground::algorithms::searching::searching::binary_search;
     ///
-    /// let arr = vec![1, 2, 3, 4, 5];
-    /// assert_eq!(binary_search(&arr, &3), Some(2));
+    /// let arr = vec![1, 2, 3, 3, 5];
+    /// assert_eq!(binary_search(&arr, &3), Ok(2));
+    /// assert_eq!(binary_search(&arr, &4), Err(4));
+    /// assert_eq!(binary_search(&arr, &0), Err(0));
+    /// assert_eq!(binary_search(&[], &1), Err(0));
     /// ```
-    
-    pub fn binary_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
-        let mut low = 0;
-        let mut high = arr.len() - 1;
+    pub fn binary_search<T: Ord>(arr: &[T], target: &T) -> Result<usize, usize> {
+        binary_search_by(arr, |probe| probe.cmp(target))
+    }
 
-        while low <= high {
-            let mid = (low + high) / 2;
-            if arr[mid] == *target {
-                return Some(mid);
-            }
-            if arr[mid] < *target {
+    /// Performs a binary search on a sorted slice with a function that compares each element it
+    /// probes to the target.
+    ///
+    /// `f` must return `Ordering::Less` for elements before the target, `Ordering::Equal` for
+    /// matching elements and `Ordering::Greater` for elements after it, in that order along the
+    /// slice. See [`binary_search`] for the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::binary_search_by;
+    ///
+    /// let arr = [5, 4, 4, 1];
+    /// assert_eq!(binary_search_by(&arr, |probe| 4.cmp(probe)), Ok(1));
+    /// assert_eq!(binary_search_by(&arr, |probe| 3.cmp(probe)), Err(3));
+    /// ```
+    pub fn binary_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let index = partition_point_by(arr, |probe| f(probe) == Ordering::Less);
+        resolve(arr, index, f)
+    }
+
+    /// Performs a binary search on a slice sorted by a key extraction function.
+    ///
+    /// See [`binary_search`] for the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::binary_search_by_key;
+    ///
+    /// let people = [("Ana", 19), ("Ben", 32), ("Cy", 32), ("Di", 47)];
+    /// assert_eq!(binary_search_by_key(&people, &32, |&(_, age)| age), Ok(1));
+    /// assert_eq!(binary_search_by_key(&people, &40, |&(_, age)| age), Err(3));
+    /// ```
+    pub fn binary_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        binary_search_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// Finds the first position in a sorted slice whose element is not less than the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of elements to search through.
+    /// * `target` - The element to find the lower bound of.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first element greater than or equal to the target, or the length of the
+    /// slice if there is none. This is the first position the target could be inserted at to
+    /// keep the slice sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::lower_bound;
+    ///
+    /// let arr = [1, 2, 2, 2, 3];
+    /// assert_eq!(lower_bound(&arr, &2), 1);
+    /// assert_eq!(lower_bound(&arr, &4), 5);
+    /// ```
+    pub fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+        lower_bound_by(arr, |probe| probe.cmp(target))
+    }
+
+    /// Finds the first position in a sorted slice whose element `f` does not order before the
+    /// target. See [`binary_search_by`] for what `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::lower_bound_by;
+    ///
+    /// let arr = [1.0f64, 2.5, 2.5, 4.0];
+    /// assert_eq!(lower_bound_by(&arr, |probe| probe.total_cmp(&2.5)), 1);
+    /// ```
+    pub fn lower_bound_by<T, F>(arr: &[T], mut f: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        partition_point_by(arr, |probe| f(probe) == Ordering::Less)
+    }
+
+    /// Finds the first position in a slice sorted by a key extraction function whose key is not
+    /// less than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::lower_bound_by_key;
+    ///
+    /// let words = ["a", "to", "be", "not", "that"];
+    /// assert_eq!(lower_bound_by_key(&words, &2, |w| w.len()), 1);
+    /// ```
+    pub fn lower_bound_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        lower_bound_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// Finds the first position in a sorted slice whose element is greater than the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of elements to search through.
+    /// * `target` - The element to find the upper bound of.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first element greater than the target, or the length of the slice if
+    /// there is none. This is the last position the target could be inserted at to keep the
+    /// slice sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::upper_bound;
+    ///
+    /// let arr = [1, 2, 2, 2, 3];
+    /// assert_eq!(upper_bound(&arr, &2), 4);
+    /// assert_eq!(upper_bound(&arr, &0), 0);
+    /// ```
+    pub fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+        upper_bound_by(arr, |probe| probe.cmp(target))
+    }
+
+    /// Finds the first position in a sorted slice whose element `f` orders after the target.
+    /// See [`binary_search_by`] for what `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::upper_bound_by;
+    ///
+    /// let arr = [1.0f64, 2.5, 2.5, 4.0];
+    /// assert_eq!(upper_bound_by(&arr, |probe| probe.total_cmp(&2.5)), 3);
+    /// ```
+    pub fn upper_bound_by<T, F>(arr: &[T], mut f: F) -> usize
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        partition_point_by(arr, |probe| f(probe) != Ordering::Greater)
+    }
+
+    /// Finds the first position in a slice sorted by a key extraction function whose key is
+    /// greater than `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::upper_bound_by_key;
+    ///
+    /// let words = ["a", "to", "be", "not", "that"];
+    /// assert_eq!(upper_bound_by_key(&words, &2, |w| w.len()), 3);
+    /// ```
+    pub fn upper_bound_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        upper_bound_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// Finds the range of positions in a sorted slice whose elements are equal to the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of elements to search through.
+    /// * `target` - The element to find the occurrences of.
+    ///
+    /// # Returns
+    ///
+    /// The range from [`lower_bound`] to [`upper_bound`]. It is empty, and starts where the
+    /// target could be inserted, if the target is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::equal_range;
+    ///
+    /// let arr = [1, 2, 2, 2, 3];
+    /// assert_eq!(equal_range(&arr, &2), 1..4);
+    /// assert_eq!(equal_range(&arr, &5), 5..5);
+    /// ```
+    pub fn equal_range<T: Ord>(arr: &[T], target: &T) -> Range<usize> {
+        equal_range_by(arr, |probe| probe.cmp(target))
+    }
+
+    /// Finds the range of positions in a sorted slice whose elements `f` considers equal to the
+    /// target. See [`binary_search_by`] for what `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::equal_range_by;
+    ///
+    /// let arr = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    /// assert_eq!(equal_range_by(&arr, |probe| probe.as_bytes()[0].cmp(&b'b')), 2..4);
+    /// ```
+    pub fn equal_range_by<T, F>(arr: &[T], mut f: F) -> Range<usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let start = lower_bound_by(arr, &mut f);
+        // Everything before `start` is less than the target, so only the rest needs searching
+        let end = start + upper_bound_by(&arr[start..], f);
+        start..end
+    }
+
+    /// Finds the range of positions in a slice sorted by a key extraction function whose keys
+    /// are equal to `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::equal_range_by_key;
+    ///
+    /// let words = ["a", "to", "be", "not", "that"];
+    /// assert_eq!(equal_range_by_key(&words, &2, |w| w.len()), 1..3);
+    /// ```
+    pub fn equal_range_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Range<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        equal_range_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// Returns the index of the first element for which `pred` is false, given that it is true
+    /// for every element before that and false for every element after.
+    fn partition_point_by<T, P>(arr: &[T], mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        // Invariant: `pred` holds before `low` and fails from `high` on
+        let mut low = 0;
+        let mut high = arr.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&arr[mid]) {
                 low = mid + 1;
             } else {
-                high = mid - 1;
+                high = mid;
             }
         }
-        None
+        low
+    }
+
+    /// Turns the lower bound of the target into the result of a search: `Ok` if the element
+    /// there matches, and `Err` otherwise.
+    fn resolve<T, F>(arr: &[T], index: usize, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        match arr.get(index) {
+            Some(probe) if f(probe) == Ordering::Equal => Ok(index),
+            _ => Err(index),
+        }
+    }
+
+    /// Performs an exponential search on a sorted slice to find a target element.
+    ///
+    /// The search probes positions 1, 2, 4, 8, ... until it passes the target, then binary
+    /// searches the last span it jumped over. It makes about 2 log2(i) comparisons when the
+    /// answer is at index `i`, so it beats [`binary_search`] when the target is near the front
+    /// of a long slice. See [`exponential_search_unbounded`] for sources whose length is not
+    /// known up front.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of elements to search through.
+    /// * `target` - The target element to search for.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`binary_search`]: `Ok` with the index of the first element equal to the
+    /// target, or `Err` with the index it could be inserted at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::exponential_search;
+    ///
+    /// let arr: Vec<u32> = (0..1_000_000).collect();
+    /// assert_eq!(exponential_search(&arr, &3), Ok(3));
+    /// assert_eq!(exponential_search(&arr, &2_000_000), Err(1_000_000));
+    /// ```
+    pub fn exponential_search<T: Ord>(arr: &[T], target: &T) -> Result<usize, usize> {
+        exponential_search_by(arr, |probe| probe.cmp(target))
+    }
+
+    /// Performs an exponential search on a sorted slice with a function that compares each
+    /// element it probes to the target. See [`exponential_search`] and [`binary_search_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::exponential_search_by;
+    ///
+    /// let arr = [9, 7, 7, 2];
+    /// assert_eq!(exponential_search_by(&arr, |probe| 7.cmp(probe)), Ok(1));
+    /// ```
+    pub fn exponential_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // After the loop, the target's lower bound is past `bound / 2 - 1` and at most `bound - 1`
+        let mut bound = 1;
+        while bound <= arr.len() && f(&arr[bound - 1]) == Ordering::Less {
+            bound = bound.saturating_mul(2);
+        }
+        let start = bound / 2;
+        let end = (bound - 1).min(arr.len());
+        let index = start + partition_point_by(&arr[start..end], |probe| f(probe) == Ordering::Less);
+        resolve(arr, index, f)
+    }
+
+    /// Performs an exponential search on a slice sorted by a key extraction function. See
+    /// [`exponential_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::exponential_search_by_key;
+    ///
+    /// let events = [(1, "boot"), (4, "login"), (9, "logout")];
+    /// assert_eq!(exponential_search_by_key(&events, &4, |&(time, _)| time), Ok(1));
+    /// ```
+    pub fn exponential_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        exponential_search_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// Performs an exponential search on a sorted sequence of unknown length, such as a file
+    /// read page by page or a stream that is still being written.
+    ///
+    /// The sequence is read through `get`, which is only called with indices up to about twice
+    /// the answer, so the search finishes even if the sequence is unbounded.
+    ///
+    /// # Arguments
+    ///
+    /// * `get` - Returns the element at an index, or `None` past the end of the sequence. Once
+    ///   it returns `None`, it must do so for every larger index.
+    /// * `target` - The target element to search for.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`binary_search`]. The insertion point may be the length of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::exponential_search_unbounded;
+    ///
+    /// // The first square of at least a million, without a bound on the sequence of squares
+    /// assert_eq!(exponential_search_unbounded(|i: usize| Some(i * i), &1_000_000), Ok(1000));
+    /// assert_eq!(exponential_search_unbounded(|i: usize| Some(i * i), &1_000_001), Err(1001));
+    ///
+    /// let arr = [2, 3, 5, 7];
+    /// assert_eq!(exponential_search_unbounded(|i| arr.get(i).copied(), &8), Err(4));
+    /// ```
+    pub fn exponential_search_unbounded<T, G>(mut get: G, target: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+        G: FnMut(usize) -> Option<T>,
+    {
+        // Past the end counts as after the target
+        let mut before = |index: usize| get(index).is_some_and(|probe| probe < *target);
+
+        let mut bound = 1;
+        while bound < usize::MAX && before(bound - 1) {
+            bound = bound.saturating_mul(2);
+        }
+        let (mut low, mut high) = (bound / 2, bound - 1);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if before(mid) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        match get(low) {
+            Some(probe) if probe == *target => Ok(low),
+            _ => Err(low),
+        }
+    }
+
+    /// A key that [`interpolation_search`] can estimate positions from, by where it falls
+    /// between two others.
+    pub trait InterpolationKey: Copy + Ord {
+        /// Returns the value as a float, rounding if it has to.
+        fn to_f64(self) -> f64;
+    }
+
+    macro_rules! impl_interpolation_key {
+        ($($t:ty),*) => {$(
+            impl InterpolationKey for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*};
+    }
+
+    impl_interpolation_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    /// Performs an interpolation search on a sorted slice of numbers to find a target element.
+    ///
+    /// Rather than probing the middle of the range the target could be in, the search probes
+    /// where the target would be if the values in the range were spread evenly between its
+    /// ends, as one opens a phone book near the back to look for "W". On uniformly distributed
+    /// keys, that takes O(log log n) comparisons on average.
+    ///
+    /// On skewed keys the estimates can be poor, so whenever a probe fails to halve the range,
+    /// the next probe is at the middle instead. That keeps the worst case at about 2 log2(n) probes,
+    /// rather than the O(n) of plain interpolation search.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of numbers to search through.
+    /// * `target` - The target number to search for.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`binary_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::interpolation_search;
+    ///
+    /// let arr: Vec<u64> = (0..1000).map(|i| i * 3).collect();
+    /// assert_eq!(interpolation_search(&arr, &1998), Ok(666));
+    /// assert_eq!(interpolation_search(&arr, &1000), Err(334));
+    /// ```
+    pub fn interpolation_search<K: InterpolationKey>(arr: &[K], target: &K) -> Result<usize, usize> {
+        interpolation_search_by_key(arr, target, |&probe| probe)
+    }
+
+    /// Performs an interpolation search on a slice sorted by a numeric key extraction function.
+    /// See [`interpolation_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::interpolation_search_by_key;
+    ///
+    /// let readings = [(100, 'a'), (200, 'b'), (300, 'c'), (400, 'd')];
+    /// assert_eq!(interpolation_search_by_key(&readings, &300, |&(time, _)| time), Ok(2));
+    /// ```
+    pub fn interpolation_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: InterpolationKey,
+        F: FnMut(&T) -> K,
+    {
+        let key = *key;
+        // Invariant: every key before `low` is less than `key`, and none from `high` on is
+        let (mut low, mut high) = (0, arr.len());
+        let mut bisect_next = false;
+        while low < high {
+            let (first, last) = (f(&arr[low]), f(&arr[high - 1]));
+            if key <= first {
+                break;
+            }
+            if key > last {
+                low = high;
+                break;
+            }
+
+            // Now first < key <= last, so the keys at the ends differ
+            let width = high - low;
+            let probe = if bisect_next {
+                low + width / 2
+            } else {
+                let fraction = (key.to_f64() - first.to_f64()) / (last.to_f64() - first.to_f64());
+                // A NaN from rounding becomes 0, and the clamp keeps the probe in range
+                low + ((fraction * (width - 1) as f64) as usize).min(width - 1)
+            };
+            if f(&arr[probe]) < key {
+                low = probe + 1;
+            } else {
+                high = probe;
+            }
+            bisect_next = !bisect_next && (high - low) * 2 > width;
+        }
+        resolve(arr, low, |probe| f(probe).cmp(&key))
+    }
+
+    /// Performs a jump search on a sorted slice to find a target element.
+    ///
+    /// The search jumps ahead sqrt(n) elements at a time until it passes the target, then
+    /// steps back through the last block one element at a time. It makes O(sqrt(n))
+    /// comparisons, more than [`binary_search`], but only ever moves forwards through the
+    /// slice except for one backward scan, which suits storage where seeking back is costly.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of elements to search through.
+    /// * `target` - The target element to search for.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`binary_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::jump_search;
+    ///
+    /// let arr = [1, 3, 3, 3, 8, 13, 21, 34, 55];
+    /// assert_eq!(jump_search(&arr, &3), Ok(1));
+    /// assert_eq!(jump_search(&arr, &20), Err(6));
+    /// ```
+    pub fn jump_search<T: Ord>(arr: &[T], target: &T) -> Result<usize, usize> {
+        jump_search_by(arr, |probe| probe.cmp(target))
+    }
+
+    /// Performs a jump search on a sorted slice with a function that compares each element it
+    /// probes to the target. See [`jump_search`] and [`binary_search_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::jump_search_by;
+    ///
+    /// let arr = ["pear", "kiwi", "fig"];
+    /// assert_eq!(jump_search_by(&arr, |probe| "kiwi".cmp(probe)), Ok(1));
+    /// ```
+    pub fn jump_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let len = arr.len();
+        // The block size only has to be close to sqrt(len), so a float square root will do
+        let step = ((len as f64).sqrt() as usize).max(1);
+        // Jump while the last element of the next block is still before the target
+        let mut start = 0;
+        while start < len && f(&arr[(start + step).min(len) - 1]) == Ordering::Less {
+            start += step;
+        }
+        let end = (start + step).min(len);
+        let mut index = end;
+        while index > start && f(&arr[index - 1]) != Ordering::Less {
+            index -= 1;
+        }
+        resolve(arr, index, f)
+    }
+
+    /// Performs a jump search on a slice sorted by a key extraction function. See
+    /// [`jump_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::jump_search_by_key;
+    ///
+    /// let words = ["a", "to", "the", "word"];
+    /// assert_eq!(jump_search_by_key(&words, &3, |s| s.len()), Ok(2));
+    /// ```
+    pub fn jump_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        jump_search_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// Performs a Fibonacci search on a sorted slice to find a target element.
+    ///
+    /// Like [`binary_search`], but the range is split where consecutive Fibonacci numbers
+    /// divide it, at about 0.618 of its length, instead of in half. The split points are
+    /// found by adding and subtracting Fibonacci numbers, with no division, which mattered on
+    /// machines where division was slow. It makes at most about 1.44 log2(n) comparisons.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - A sorted slice of elements to search through.
+    /// * `target` - The target element to search for.
+    ///
+    /// # Returns
+    ///
+    /// The same as [`binary_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::fibonacci_search;
+    ///
+    /// let arr = [10, 22, 35, 40, 45, 50, 80, 82, 85, 90, 100];
+    /// assert_eq!(fibonacci_search(&arr, &85), Ok(8));
+    /// assert_eq!(fibonacci_search(&arr, &11), Err(1));
+    /// ```
+    pub fn fibonacci_search<T: Ord>(arr: &[T], target: &T) -> Result<usize, usize> {
+        fibonacci_search_by(arr, |probe| probe.cmp(target))
+    }
+
+    /// Performs a Fibonacci search on a sorted slice with a function that compares each element
+    /// it probes to the target. See [`fibonacci_search`] and [`binary_search_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::fibonacci_search_by;
+    ///
+    /// let arr = [50, 40, 40, 10];
+    /// assert_eq!(fibonacci_search_by(&arr, |probe| 40.cmp(probe)), Ok(1));
+    /// ```
+    pub fn fibonacci_search_by<T, F>(arr: &[T], mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // `fib` is the largest Fibonacci number no greater than the length of the range, and
+        // `prev` the one before it
+        let (mut prev, mut fib) = (1usize, 1usize);
+        while prev + fib <= arr.len() {
+            (prev, fib) = (fib, prev + fib);
+        }
+
+        // Invariant: every element before `low` is before the target, and none from `high` on
+        let (mut low, mut high) = (0, arr.len());
+        while low < high {
+            while fib > high - low {
+                (prev, fib) = (fib - prev, prev);
+            }
+            let probe = low + fib - 1;
+            if f(&arr[probe]) == Ordering::Less {
+                low = probe + 1;
+            } else {
+                high = probe;
+            }
+        }
+        resolve(arr, low, f)
+    }
+
+    /// Performs a Fibonacci search on a slice sorted by a key extraction function. See
+    /// [`fibonacci_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::fibonacci_search_by_key;
+    ///
+    /// let scores = [("Kim", 61), ("Lee", 74), ("Max", 88)];
+    /// assert_eq!(fibonacci_search_by_key(&scores, &74, |&(_, score)| score), Ok(1));
+    /// ```
+    pub fn fibonacci_search_by_key<T, K, F>(arr: &[T], key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        fibonacci_search_by(arr, |probe| f(probe).cmp(key))
+    }
+
+    /// An integer type whose ranges [`partition_point`] can search.
+    pub trait Bisect: Copy + Ord {
+        /// Returns the value halfway between `low` and `high`, rounded down, without overflowing.
+        fn midpoint(low: Self, high: Self) -> Self;
+
+        /// Returns the value after `self`, which must not be the largest value of the type.
+        fn successor(self) -> Self;
+    }
+
+    macro_rules! impl_bisect {
+        ($($t:ty),*) => {$(
+            impl Bisect for $t {
+                fn midpoint(low: Self, high: Self) -> Self {
+                    // The shared bits plus half the differing ones, which rounds down for signed
+                    // types too since the shift is arithmetic
+                    (low & high) + ((low ^ high) >> 1)
+                }
+
+                fn successor(self) -> Self {
+                    self + 1
+                }
+            }
+        )*};
+    }
+
+    impl_bisect!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    /// Performs a binary search over a range of integers for the point where a predicate stops
+    /// holding.
+    ///
+    /// This is [`lower_bound`] for an implicit sorted sequence, such as the answers to "does a
+    /// capacity of `c` suffice?", where the predicate is too expensive to evaluate for every
+    /// value. It is evaluated at most about log2(hi - lo) + 1 times, and the search never
+    /// overflows, even over the whole range of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The values to search, `lo..hi`.
+    /// * `pred` - A predicate that holds for every value before some point and for none after it.
+    ///
+    /// # Returns
+    ///
+    /// The first value in the range for which the predicate does not hold, or `hi` if it holds
+    /// for all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::partition_point;
+    ///
+    /// // The smallest truck capacity that ships these parcels, in order, in at most three trips
+    /// let parcels = [7, 2, 5, 10, 8];
+    /// let trips = |capacity: u32| {
+    ///     let (mut trips, mut load) = (1, 0);
+    ///     for &parcel in &parcels {
+    ///         if load + parcel > capacity {
+    ///             trips += 1;
+    ///             load = 0;
+    ///         }
+    ///         load += parcel;
+    ///     }
+    ///     trips
+    /// };
+    /// assert_eq!(partition_point(10..32, |&capacity| trips(capacity) > 3), 14);
+    ///
+    /// assert_eq!(partition_point(i64::MIN..i64::MAX, |&x| x < -5), -5);
+    /// ```
+    pub fn partition_point<T, P>(range: Range<T>, mut pred: P) -> T
+    where
+        T: Bisect,
+        P: FnMut(&T) -> bool,
+    {
+        // Invariant: `pred` holds before `low` and fails from `high` on
+        let Range { start: mut low, end: mut high } = range;
+        while low < high {
+            let mid = T::midpoint(low, high);
+            if pred(&mid) {
+                low = mid.successor();
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// When a search over real numbers stops.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Tolerance {
+        /// The search stops once the interval it has narrowed down to is no wider than this.
+        pub width: f64,
+        /// The search gives up after this many steps.
+        pub max_iterations: usize,
+    }
+
+    impl Tolerance {
+        /// Creates a tolerance of `width`, with a limit of 2100 steps, enough for [`bisect`] to
+        /// narrow any finite interval down to adjacent floats. Halving `-f64::MAX..f64::MAX`
+        /// down to the subnormals around zero takes 2099.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `width` is negative or NaN.
+        pub fn new(width: f64) -> Self {
+            assert!(width >= 0.0, "tolerance must not be negative");
+            Tolerance { width, max_iterations: 2100 }
+        }
+    }
+
+    impl Default for Tolerance {
+        /// A width of 1e-9.
+        fn default() -> Self {
+            Tolerance::new(1e-9)
+        }
+    }
+
+    /// The interval a search over real numbers narrowed its answer down to.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Bracket {
+        /// The lower end of the interval.
+        pub low: f64,
+        /// The upper end of the interval.
+        pub high: f64,
+        /// The number of steps the search took.
+        pub iterations: usize,
+        /// Whether the interval is within the tolerance, or as narrow as `f64` allows, rather
+        /// than the search having run out of steps.
+        pub converged: bool,
+    }
+
+    impl Bracket {
+        /// Returns the middle of the interval, the best single estimate of the answer.
+        pub fn midpoint(&self) -> f64 {
+            point_between(self.low, self.high, 0.5)
+        }
+
+        /// Returns the width of the interval.
+        pub fn width(&self) -> f64 {
+            self.high - self.low
+        }
+    }
+
+    /// Returns the point a fraction `t` of the way from `low` to `high`, even when the width of
+    /// the interval is too large for an `f64`.
+    fn point_between(low: f64, high: f64, t: f64) -> f64 {
+        let width = high - low;
+        if width.is_finite() {
+            low + width * t
+        } else {
+            // Both ends are huge, so weighting them separately loses nothing and stays between them
+            low * (1.0 - t) + high * t
+        }
+    }
+
+    /// Performs a bisection over real numbers for the point where a predicate stops holding.
+    ///
+    /// Each step evaluates the predicate at the middle of the interval and keeps the half the
+    /// point is in, until the interval is within the tolerance or the step limit is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `low`, `high` - The ends of the interval to search.
+    /// * `tolerance` - When to stop.
+    /// * `pred` - A predicate that holds below some point of the interval and fails above it.
+    ///
+    /// # Returns
+    ///
+    /// An interval around the point where the predicate stops holding. If it holds everywhere,
+    /// the interval is at `high`, and if it holds nowhere, at `low`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high`, or either is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::{bisect, Tolerance};
+    ///
+    /// let sqrt2 = bisect(0.0, 2.0, &Tolerance::new(1e-12), |x| x * x < 2.0);
+    /// assert!(sqrt2.converged);
+    /// assert!((sqrt2.midpoint() - 2f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn bisect<P>(mut low: f64, mut high: f64, tolerance: &Tolerance, mut pred: P) -> Bracket
+    where
+        P: FnMut(f64) -> bool,
+    {
+        assert!(low <= high, "the interval {}..{} is empty", low, high);
+        let mut iterations = 0;
+        let converged = loop {
+            if high - low <= tolerance.width {
+                break true;
+            }
+            let mid = point_between(low, high, 0.5);
+            // The ends are adjacent floats, so there is nothing between them to try
+            if mid <= low || mid >= high {
+                break true;
+            }
+            if iterations == tolerance.max_iterations {
+                break false;
+            }
+            iterations += 1;
+            if pred(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        };
+        Bracket { low, high, iterations, converged }
+    }
+
+    /// Finds a root of a continuous function by bisection, given an interval over which it
+    /// changes sign.
+    ///
+    /// # Returns
+    ///
+    /// An interval around a root, or `None` if `f(low)` and `f(high)` have the same sign, in
+    /// which case there may be no root to find. If `f` is zero at either end, the interval is
+    /// just that end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high`, or either is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::{find_root, Tolerance};
+    ///
+    /// let root = find_root(1.0, 2.0, &Tolerance::default(), |x| x.cos() - x / 2.0).unwrap();
+    /// assert!((root.midpoint() - 1.029866529).abs() < 1e-8);
+    /// assert_eq!(find_root(1.0, 2.0, &Tolerance::default(), |x| x * x + 1.0), None);
+    /// assert_eq!(find_root(0.0, 1.0, &Tolerance::default(), |x| x).unwrap().width(), 0.0);
+    /// ```
+    pub fn find_root<F>(low: f64, high: f64, tolerance: &Tolerance, mut f: F) -> Option<Bracket>
+    where
+        F: FnMut(f64) -> f64,
+    {
+        assert!(low <= high, "the interval {}..{} is empty", low, high);
+        let (f_low, f_high) = (f(low), f(high));
+        for (end, value) in [(low, f_low), (high, f_high)] {
+            if value == 0.0 {
+                return Some(Bracket { low: end, high: end, iterations: 0, converged: true });
+            }
+        }
+
+        let low_sign = f_low.is_sign_negative();
+        if low_sign == f_high.is_sign_negative() {
+            return None;
+        }
+        // A root is where the sign last matches the sign at `low`
+        Some(bisect(low, high, tolerance, |x| f(x).is_sign_negative() == low_sign))
+    }
+
+    /// Finds the minimum of a unimodal function using ternary search.
+    ///
+    /// A unimodal function decreases up to its minimum and increases after it. Each step
+    /// evaluates the function at the two points that split the interval into thirds, and drops
+    /// the third beyond the larger of the two values, which cannot hold the minimum. To find a
+    /// maximum, negate the function.
+    ///
+    /// Each step takes two evaluations and keeps two thirds of the interval. See
+    /// [`golden_section_search`] for a search that needs fewer evaluations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high`, or either is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::{ternary_search, Tolerance};
+    ///
+    /// let min = ternary_search(-10.0, 10.0, &Tolerance::new(1e-9), |x| (x - 1.5) * (x - 1.5));
+    /// assert!((min.midpoint() - 1.5).abs() < 1e-9);
+    /// ```
+    pub fn ternary_search<F>(mut low: f64, mut high: f64, tolerance: &Tolerance, mut f: F) -> Bracket
+    where
+        F: FnMut(f64) -> f64,
+    {
+        assert!(low <= high, "the interval {}..{} is empty", low, high);
+        let mut iterations = 0;
+        let converged = loop {
+            if high - low <= tolerance.width {
+                break true;
+            }
+            let (left, right) = (point_between(low, high, 1.0 / 3.0), point_between(low, high, 2.0 / 3.0));
+            if left <= low || right >= high {
+                break true;
+            }
+            if iterations == tolerance.max_iterations {
+                break false;
+            }
+            iterations += 1;
+            if f(left) < f(right) {
+                high = right;
+            } else {
+                low = left;
+            }
+        };
+        Bracket { low, high, iterations, converged }
+    }
+
+    /// Finds the minimum of a unimodal function using golden-section search.
+    ///
+    /// Like [`ternary_search`], but the two points each step evaluates split the interval in
+    /// the golden ratio, so that one of them is exactly where one of the next step's points
+    /// has to go. Each step after the first normally takes a single evaluation and keeps about
+    /// 61.8% of the interval, which narrows it faster per evaluation than ternary search. To
+    /// find a maximum, negate the function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high`, or either is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::searching::searching::{golden_section_search, Tolerance};
+    ///
+    /// // The angle that throws a ball furthest
+    /// let best = golden_section_search(0.0, 1.5, &Tolerance::new(1e-9), |angle| -(2.0 * angle).sin());
+    /// assert!((best.midpoint() - std::f64::consts::FRAC_PI_4).abs() < 1e-8);
+    /// ```
+    pub fn golden_section_search<F>(mut low: f64, mut high: f64, tolerance: &Tolerance, mut f: F) -> Bracket
+    where
+        F: FnMut(f64) -> f64,
+    {
+        assert!(low <= high, "the interval {}..{} is empty", low, high);
+        // 1 / phi, the fraction of the interval each step keeps
+        let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+
+        let mut left = point_between(low, high, 1.0 - inv_phi);
+        let mut right = point_between(low, high, inv_phi);
+        let (mut f_left, mut f_right) = (f(left), f(right));
+        let mut iterations = 0;
+        let converged = loop {
+            if high - low <= tolerance.width {
+                break true;
+            }
+            if left <= low || right >= high || left >= right {
+                // The point kept from the last step still carries the rounding error of the
+                // wider intervals before it, and has drifted out of place, so start afresh
+                left = point_between(low, high, 1.0 - inv_phi);
+                right = point_between(low, high, inv_phi);
+                if left <= low || right >= high || left >= right {
+                    break true;
+                }
+                (f_left, f_right) = (f(left), f(right));
+            }
+            if iterations == tolerance.max_iterations {
+                break false;
+            }
+            iterations += 1;
+            if f_left < f_right {
+                // The minimum is in low..right: the old left point becomes the new right one
+                high = right;
+                right = left;
+                f_right = f_left;
+                left = point_between(low, high, 1.0 - inv_phi);
+                f_left = f(left);
+            } else {
+                low = left;
+                left = right;
+                f_left = f_right;
+                right = point_between(low, high, inv_phi);
+                f_right = f(right);
+            }
+        };
+        Bracket { low, high, iterations, converged }
     }
 
     /// Example graph representation using an adjacency list
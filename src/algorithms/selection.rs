@@ -0,0 +1,441 @@
+//! # Selection
+//!
+//! `selection` finds the k-th smallest element, or the k smallest or largest elements, of a
+//! slice or iterator without sorting all of it.
+
+pub mod selection {
+    use std::cmp::Ordering;
+
+    use crate::algorithms::sorting::sorting::{
+        choose_pivot, heap_sort_impl, heapify, insertion_sort_impl, partition, NoObserver, SortObserver,
+        SortOps,
+    };
+
+    /// Subslices of at most this length are finished off with insertion sort.
+    const INSERTION_SORT_THRESHOLD: usize = 16;
+
+    /// How many times the slice length quickselect may spend partitioning with cheap pivots.
+    const CHEAP_PIVOT_BUDGET: usize = 4;
+
+    /// Reorders the slice so that the element at index `k` is the one that would be there if
+    /// the slice were sorted, and returns a reference to it.
+    ///
+    /// Like C++'s `nth_element`, every element before index `k` is less than or equal to it and
+    /// every element after it is greater than or equal to it, in no particular order. This is
+    /// an introselect: a quickselect using median-of-three (or ninther) pivots, which switches to
+    /// median-of-medians pivots once it has partitioned more than `4 * n` elements in total
+    /// without finding the answer. This keeps the worst case at O(n) comparisons.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::select_nth;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// assert_eq!(*select_nth(&mut arr, 1), 2);
+    /// assert!(arr[..1].iter().all(|&x| x <= 2));
+    /// assert!(arr[2..].iter().all(|&x| x >= 2));
+    /// ```
+    pub fn select_nth<T: Ord>(arr: &mut [T], k: usize) -> &mut T {
+        select_nth_by(arr, k, T::cmp)
+    }
+
+    /// Reorders the slice around its `k`-th smallest element according to a comparator
+    /// function, and returns a reference to it. See [`select_nth`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::select_nth_by;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// assert_eq!(*select_nth_by(&mut arr, 0, |a, b| b.cmp(a)), 5);
+    /// ```
+    pub fn select_nth_by<T, F>(arr: &mut [T], k: usize, mut compare: F) -> &mut T
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        assert!(k < arr.len(), "select_nth index {} out of range for slice of length {}", k, arr.len());
+        select_impl(arr, k, &mut SortOps::new(&mut compare, &mut NoObserver));
+        &mut arr[k]
+    }
+
+    /// Reorders the slice around its `k`-th smallest element according to a key extraction
+    /// function, and returns a reference to it. See [`select_nth`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::select_nth_by_key;
+    ///
+    /// let mut arr = [-5, 1, -4, 2, 3];
+    /// assert_eq!(*select_nth_by_key(&mut arr, 4, |x: &i32| x.abs()), -5);
+    /// ```
+    pub fn select_nth_by_key<T, K, F>(arr: &mut [T], k: usize, mut f: F) -> &mut T
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        select_nth_by(arr, k, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns the median of the slice, reordering it as [`select_nth`] does, or `None` if the
+    /// slice is empty.
+    ///
+    /// For an even number of elements this is the lower of the two middle elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::median;
+    ///
+    /// assert_eq!(median(&mut [3, 1, 2]), Some(&2));
+    /// assert_eq!(median(&mut [4, 1, 3, 2]), Some(&2));
+    /// assert_eq!(median::<i32>(&mut []), None);
+    /// ```
+    pub fn median<T: Ord>(arr: &mut [T]) -> Option<&T> {
+        if arr.is_empty() {
+            return None;
+        }
+        let k = (arr.len() - 1) / 2;
+        Some(select_nth(arr, k))
+    }
+
+    /// Returns the `p`-th percentile of the slice using the nearest-rank method, reordering it
+    /// as [`select_nth`] does, or `None` if the slice is empty.
+    ///
+    /// The result is the smallest element such that at least `p` percent of the slice is less
+    /// than or equal to it. The 0th percentile is the minimum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not between 0 and 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::percentile;
+    ///
+    /// let mut arr = [15, 20, 35, 40, 50];
+    /// assert_eq!(percentile(&mut arr, 30.0), Some(&20));
+    /// assert_eq!(percentile(&mut arr, 100.0), Some(&50));
+    /// ```
+    pub fn percentile<T: Ord>(arr: &mut [T], p: f64) -> Option<&T> {
+        assert!((0.0..=100.0).contains(&p), "percentile {} is not between 0 and 100", p);
+        if arr.is_empty() {
+            return None;
+        }
+        let rank = (p / 100.0 * arr.len() as f64).ceil() as usize;
+        let k = rank.clamp(1, arr.len()) - 1;
+        Some(select_nth(arr, k))
+    }
+
+    /// Reorders the slice so that its first `k` elements are its `k` smallest, in sorted order.
+    /// The order of the remaining elements is unspecified.
+    ///
+    /// This keeps a max-heap of the `k` smallest elements seen so far at the front of the
+    /// slice, replacing its top whenever a smaller element turns up, and then sorts the heap.
+    /// It takes O(n log k) time instead of the O(n log n) of a full sort. If `k` is at least
+    /// the length of the slice, the whole slice is sorted. The sort is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::partial_sort;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// partial_sort(&mut arr, 2);
+    /// assert_eq!(arr[..2], [1, 2]);
+    /// ```
+    pub fn partial_sort<T: Ord>(arr: &mut [T], k: usize) {
+        partial_sort_by(arr, k, T::cmp);
+    }
+
+    /// Reorders the slice so that its first `k` elements are its `k` smallest according to a
+    /// comparator function, in sorted order. See [`partial_sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::partial_sort_by;
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// partial_sort_by(&mut arr, 2, |a, b| b.cmp(a));
+    /// assert_eq!(arr[..2], [5, 4]);
+    /// ```
+    pub fn partial_sort_by<T, F>(arr: &mut [T], k: usize, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        partial_sort_impl(arr, k, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Reorders the slice so that its first `k` elements are its `k` smallest according to a
+    /// key extraction function, in sorted order. See [`partial_sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::partial_sort_by_key;
+    ///
+    /// let mut arr = [-5, 1, -4, 2, 3];
+    /// partial_sort_by_key(&mut arr, 2, |x: &i32| x.abs());
+    /// assert_eq!(arr[..2], [1, 2]);
+    /// ```
+    pub fn partial_sort_by_key<T, K, F>(arr: &mut [T], k: usize, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        partial_sort_by(arr, k, |a, b| f(a).cmp(&f(b)));
+    }
+
+    fn partial_sort_impl<T, F, O>(arr: &mut [T], k: usize, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let k = k.min(arr.len());
+        if k == 0 {
+            return;
+        }
+
+        // Build a max-heap from the first k elements
+        for i in (0..k / 2).rev() {
+            heapify(arr, k, i, &mut ops.nested(0));
+        }
+
+        // Swap every smaller element after the heap into it in place of its largest
+        for i in k..arr.len() {
+            if ops.compare(arr, i, 0) == Ordering::Less {
+                ops.swap(arr, 0, i);
+                heapify(arr, k, 0, &mut ops.nested(0));
+            }
+        }
+
+        heap_sort_impl(&mut arr[..k], ops);
+    }
+
+    /// Returns the `k` largest items of an iterator, largest first.
+    ///
+    /// The iterator is consumed one item at a time while only the `k` largest items seen so far
+    /// are kept, in a min-heap, so memory use is O(k) however long the iterator is and the
+    /// running time is O(n log k). Equal items may be returned in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::top_k;
+    ///
+    /// assert_eq!(top_k([3, 9, 1, 7, 5], 2), [9, 7]);
+    /// assert_eq!(top_k(0..1_000_000u64, 3), [999_999, 999_998, 999_997]);
+    /// ```
+    pub fn top_k<I>(iter: I, k: usize) -> Vec<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Ord,
+    {
+        top_k_by(iter, k, I::Item::cmp)
+    }
+
+    /// Returns the `k` largest items of an iterator according to a comparator function, largest
+    /// first. See [`top_k`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::top_k_by;
+    ///
+    /// // The three smallest items
+    /// assert_eq!(top_k_by([5, 1, 4, 2, 3], 3, |a, b| b.cmp(a)), [1, 2, 3]);
+    /// ```
+    pub fn top_k_by<I, F>(iter: I, k: usize, mut compare: F) -> Vec<I::Item>
+    where
+        I: IntoIterator,
+        F: FnMut(&I::Item, &I::Item) -> Ordering,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        // A max-heap under the reversed order is a min-heap, with the smallest kept item on top
+        let mut reversed = |a: &I::Item, b: &I::Item| compare(b, a);
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut reversed, &mut observer);
+
+        let mut heap = Vec::with_capacity(k + 1);
+        for item in iter {
+            heap.push(item);
+            let len = heap.len();
+            if len <= k {
+                if len == k {
+                    for i in (0..k / 2).rev() {
+                        heapify(&mut heap, k, i, &mut ops.nested(0));
+                    }
+                }
+                continue;
+            }
+
+            // Replace the top of the heap if the new item is larger, then drop the smaller one
+            if ops.compare(&heap, 0, k) == Ordering::Greater {
+                ops.swap(&mut heap, 0, k);
+                heapify(&mut heap, k, 0, &mut ops.nested(0));
+            }
+            heap.pop();
+        }
+
+        heap_sort_impl(&mut heap, &mut ops);
+        heap
+    }
+
+    /// Returns the `k` items of an iterator with the largest keys according to a key extraction
+    /// function, largest first. See [`top_k`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::selection::selection::top_k_by_key;
+    ///
+    /// let rows = [("a", 30), ("b", 10), ("c", 50), ("d", 20)];
+    /// assert_eq!(top_k_by_key(rows, 2, |&(_, hits)| hits), [("c", 50), ("a", 30)]);
+    /// ```
+    pub fn top_k_by_key<I, K, F>(iter: I, k: usize, mut f: F) -> Vec<I::Item>
+    where
+        I: IntoIterator,
+        K: Ord,
+        F: FnMut(&I::Item) -> K,
+    {
+        top_k_by(iter, k, |a, b| f(a).cmp(&f(b)))
+    }
+
+    fn select_impl<T, F, O>(mut arr: &mut [T], mut k: usize, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        // Cheap pivots may partition this many elements in total before median of medians takes
+        // over, which bounds the cost of bad luck or an adversarial input
+        let mut cheap_budget = CHEAP_PIVOT_BUDGET * arr.len();
+        // Where the subslice being worked on starts within `ops`' subslice
+        let mut offset = 0;
+        loop {
+            let len = arr.len();
+            if len <= INSERTION_SORT_THRESHOLD {
+                insertion_sort_impl(arr, &mut ops.at(offset));
+                return;
+            }
+
+            let pivot_index = if cheap_budget >= len {
+                cheap_budget -= len;
+                choose_pivot(arr, &mut ops.at(offset))
+            } else {
+                median_of_medians(arr, &mut ops.at(offset))
+            };
+            let (equal_start, equal_end) = partition_equal(arr, pivot_index, k, &mut ops.at(offset));
+
+            // Continue into whichever side holds index `k`
+            if k < equal_start {
+                arr = &mut arr[..equal_start];
+            } else if k >= equal_end {
+                arr = &mut arr[equal_end..];
+                k -= equal_end;
+                offset += equal_end;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Partitions the slice around the element at `pivot_index`, and returns the range of
+    /// elements equal to the pivot if index `k` lands left of the pivot, or just the pivot's
+    /// own position otherwise.
+    ///
+    /// Gathering the equal elements keeps selection linear when the slice is full of
+    /// duplicates, since `partition` puts all of them left of the pivot.
+    fn partition_equal<T, F, O>(arr: &mut [T], pivot_index: usize, k: usize, ops: &mut SortOps<T, F, O>) -> (usize, usize)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let pivot = partition(arr, pivot_index, ops);
+        if k >= pivot {
+            return (pivot, pivot + 1);
+        }
+
+        // Move the elements equal to the pivot to the end of the left side
+        let mut equal_start = pivot;
+        for j in (0..pivot).rev() {
+            if ops.compare(arr, j, pivot) == Ordering::Equal {
+                equal_start -= 1;
+                ops.swap(arr, j, equal_start);
+            }
+        }
+        (equal_start, pivot + 1)
+    }
+
+    /// Returns the index of a pivot that is guaranteed to have at least 30% of the slice on
+    /// each side of it: the median of the medians of groups of five.
+    fn median_of_medians<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        if len <= 5 {
+            insertion_sort_impl(arr, ops);
+            return len / 2;
+        }
+
+        // Sort each full group of five and move its median to the front of the slice
+        let groups = len / 5;
+        for group in 0..groups {
+            let start = group * 5;
+            insertion_sort_impl(&mut arr[start..start + 5], &mut ops.at(start));
+            ops.swap(arr, group, start + 2);
+        }
+
+        // Select the median of the medians, which now fill the front of the slice
+        let mid = groups / 2;
+        select_median_of_medians(&mut arr[..groups], mid, &mut ops.nested(0));
+        mid
+    }
+
+    /// Selects the `k`-th smallest element using only median-of-medians pivots.
+    fn select_median_of_medians<T, F, O>(mut arr: &mut [T], mut k: usize, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let mut offset = 0;
+        loop {
+            if arr.len() <= 5 {
+                insertion_sort_impl(arr, &mut ops.at(offset));
+                return;
+            }
+
+            let pivot_index = median_of_medians(arr, &mut ops.at(offset));
+            let (equal_start, equal_end) = partition_equal(arr, pivot_index, k, &mut ops.at(offset));
+            if k < equal_start {
+                arr = &mut arr[..equal_start];
+            } else if k >= equal_end {
+                arr = &mut arr[equal_end..];
+                k -= equal_end;
+                offset += equal_end;
+            } else {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! # Sortedness
+//!
+//! `sortedness` measures how far a slice is from being sorted, which helps decide which
+//! sorting algorithm suits it.
+
+pub mod sortedness {
+    use std::cmp::Ordering;
+    use std::mem;
+
+    /// Measures of how far a slice is from being sorted.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct SortednessReport {
+        /// The number of elements in the slice.
+        pub len: usize,
+        /// The number of pairs of elements that are out of order.
+        pub inversions: u64,
+        /// The number of maximal non-descending runs the slice splits into. A sorted slice has
+        /// one run, and an empty one has none.
+        pub runs: usize,
+        /// The length of the longest non-descending run.
+        pub longest_run: usize,
+        /// The furthest any element is from where a stable sort would put it.
+        pub max_displacement: usize,
+    }
+
+    impl SortednessReport {
+        /// Returns whether the slice is sorted.
+        pub fn is_sorted(&self) -> bool {
+            self.inversions == 0
+        }
+
+        /// Returns whether the slice has at most as many inversions as elements.
+        ///
+        /// Insertion sort takes O(n + inversions) time, so on such a slice it runs in linear
+        /// time and beats the O(n log n) sorts.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use algorithm_playground::algorithms::sortedness::sortedness::sortedness_report;
+        ///
+        /// assert!(sortedness_report(&[1, 2, 4, 3, 5, 6]).is_nearly_sorted());
+        /// assert!(!sortedness_report(&[6, 5, 4, 3, 2, 1]).is_nearly_sorted());
+        /// ```
+        pub fn is_nearly_sorted(&self) -> bool {
+            self.inversions <= self.len as u64
+        }
+    }
+
+    /// Returns whether the slice is sorted in non-descending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sortedness::sortedness::is_sorted;
+    ///
+    /// assert!(is_sorted(&[1, 2, 2, 3]));
+    /// assert!(!is_sorted(&[1, 3, 2]));
+    /// ```
+    pub fn is_sorted<T: Ord>(arr: &[T]) -> bool {
+        is_sorted_by(arr, T::cmp)
+    }
+
+    /// Returns whether the slice is sorted according to a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sortedness::sortedness::is_sorted_by;
+    ///
+    /// assert!(is_sorted_by(&[3, 2, 1], |a, b| b.cmp(a)));
+    /// ```
+    pub fn is_sorted_by<T, F>(arr: &[T], mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        arr.windows(2).all(|w| compare(&w[0], &w[1]) != Ordering::Greater)
+    }
+
+    /// Returns whether the slice is sorted according to a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sortedness::sortedness::is_sorted_by_key;
+    ///
+    /// assert!(is_sorted_by_key(&[1, -2, 3], |x: &i32| x.abs()));
+    /// ```
+    pub fn is_sorted_by_key<T, K, F>(arr: &[T], mut f: F) -> bool
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        is_sorted_by(arr, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns the length of the longest non-descending run of consecutive elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sortedness::sortedness::longest_sorted_run;
+    ///
+    /// assert_eq!(longest_sorted_run(&[3, 1, 2, 2, 5, 4]), 4);
+    /// assert_eq!(longest_sorted_run::<i32>(&[]), 0);
+    /// ```
+    pub fn longest_sorted_run<T: Ord>(arr: &[T]) -> usize {
+        longest_sorted_run_by(arr, T::cmp)
+    }
+
+    /// Returns the length of the longest run of consecutive elements that is sorted according
+    /// to a comparator function.
+    pub fn longest_sorted_run_by<T, F>(arr: &[T], compare: F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (_, longest) = count_runs(arr, compare);
+        longest
+    }
+
+    /// Returns the number of inversions in the slice: pairs of elements where the larger one
+    /// comes first.
+    ///
+    /// The slice is left untouched. The inversions are counted while merge sorting a list of
+    /// indices into it, which takes O(n log n) time and O(n) extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sortedness::sortedness::count_inversions;
+    ///
+    /// assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+    /// assert_eq!(count_inversions(&[5, 4, 3, 2, 1]), 10);
+    /// ```
+    pub fn count_inversions<T: Ord>(arr: &[T]) -> u64 {
+        count_inversions_by(arr, T::cmp)
+    }
+
+    /// Returns the number of inversions in the slice according to a comparator function. See
+    /// [`count_inversions`].
+    pub fn count_inversions_by<T, F>(arr: &[T], compare: F) -> u64
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (_, inversions) = sorted_order(arr, compare);
+        inversions
+    }
+
+    /// Measures how far the slice is from being sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sortedness::sortedness::{sortedness_report, SortednessReport};
+    ///
+    /// let report = sortedness_report(&[1, 2, 6, 3, 4, 5]);
+    /// assert_eq!(
+    ///     report,
+    ///     SortednessReport { len: 6, inversions: 3, runs: 2, longest_run: 3, max_displacement: 3 }
+    /// );
+    /// ```
+    pub fn sortedness_report<T: Ord>(arr: &[T]) -> SortednessReport {
+        sortedness_report_by(arr, T::cmp)
+    }
+
+    /// Measures how far the slice is from being sorted according to a comparator function. See
+    /// [`sortedness_report`].
+    pub fn sortedness_report_by<T, F>(arr: &[T], mut compare: F) -> SortednessReport
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (runs, longest_run) = count_runs(arr, &mut compare);
+        let (order, inversions) = sorted_order(arr, &mut compare);
+        let max_displacement = order.iter().enumerate().map(|(to, &from)| to.abs_diff(from)).max().unwrap_or(0);
+        SortednessReport {
+            len: arr.len(),
+            inversions,
+            runs,
+            longest_run,
+            max_displacement,
+        }
+    }
+
+    /// Returns the number of maximal non-descending runs and the length of the longest one.
+    fn count_runs<T, F>(arr: &[T], mut compare: F) -> (usize, usize)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if arr.is_empty() {
+            return (0, 0);
+        }
+
+        let mut runs = 1;
+        let mut longest = 1;
+        let mut current = 1;
+        for w in arr.windows(2) {
+            if compare(&w[0], &w[1]) == Ordering::Greater {
+                runs += 1;
+                current = 1;
+            } else {
+                current += 1;
+                longest = longest.max(current);
+            }
+        }
+        (runs, longest)
+    }
+
+    /// Stably merge sorts the indices of the slice's elements, counting inversions along the
+    /// way, and returns the sorted indices along with the count.
+    ///
+    /// Whenever an element of the right run is merged ahead of elements still left in the left
+    /// run, it forms an inversion with each of them.
+    fn sorted_order<T, F>(arr: &[T], mut compare: F) -> (Vec<usize>, u64)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let mut order: Vec<usize> = (0..len).collect();
+        let mut buf = vec![0; len];
+        let mut inversions = 0;
+
+        let mut width = 1;
+        while width < len {
+            for start in (0..len).step_by(2 * width) {
+                let mid = (start + width).min(len);
+                let end = (start + 2 * width).min(len);
+                let (mut i, mut j, mut k) = (start, mid, start);
+                while i < mid && j < end {
+                    if compare(&arr[order[j]], &arr[order[i]]) == Ordering::Less {
+                        buf[k] = order[j];
+                        j += 1;
+                        inversions += (mid - i) as u64;
+                    } else {
+                        buf[k] = order[i];
+                        i += 1;
+                    }
+                    k += 1;
+                }
+                buf[k..k + mid - i].copy_from_slice(&order[i..mid]);
+                k += mid - i;
+                buf[k..end].copy_from_slice(&order[j..end]);
+            }
+            mem::swap(&mut order, &mut buf);
+            width *= 2;
+        }
+        (order, inversions)
+    }
+}
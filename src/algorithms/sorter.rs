@@ -0,0 +1,263 @@
+//! # Sorter
+//!
+//! `sorter` describes the sorting algorithms through a common trait, so that they can be
+//! chosen by their properties and used interchangeably.
+
+pub mod sorter {
+    use std::cmp::Ordering;
+    use std::fmt;
+
+    use crate::algorithms::sorting::sorting::{
+        bubble_sort_by, cocktail_shaker_sort_by, comb_sort_by, cycle_sort_by, gnome_sort_by, heap_sort_by,
+        insertion_sort_by, merge_sort_by, odd_even_sort_by, quick_sort_by, selection_sort_by, shell_sort_by,
+    };
+
+    /// How the running time of an algorithm grows with the length `n` of its input.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Complexity {
+        /// O(n)
+        Linear,
+        /// O(n log n)
+        Linearithmic,
+        /// o(n^2): grows more slowly than n^2, with no known tight bound, like shell sort with
+        /// gaps found by experiment.
+        Subquadratic,
+        /// O(n^2)
+        Quadratic,
+    }
+
+    impl fmt::Display for Complexity {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let notation = match self {
+                Complexity::Linear => "O(n)",
+                Complexity::Linearithmic => "O(n log n)",
+                Complexity::Subquadratic => "o(n^2)",
+                Complexity::Quadratic => "O(n^2)",
+            };
+            f.write_str(notation)
+        }
+    }
+
+    /// A sorting algorithm, along with the guarantees it makes.
+    ///
+    /// The metadata methods can be called through `dyn Sorter`, so that algorithms can be
+    /// listed and compared. The sorting methods need a concrete type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorter::sorter::{Complexity, MergeSort, QuickSort, Sorter};
+    ///
+    /// let sorters: [&dyn Sorter; 2] = [&MergeSort, &QuickSort];
+    /// let stable: Vec<&str> = sorters.iter().filter(|s| s.is_stable()).map(|s| s.name()).collect();
+    /// assert_eq!(stable, ["merge sort"]);
+    ///
+    /// let mut arr = [3, 1, 2];
+    /// QuickSort.sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// assert_eq!(QuickSort.worst_case(), Complexity::Linearithmic);
+    /// ```
+    pub trait Sorter {
+        /// The name of the algorithm.
+        fn name(&self) -> &'static str;
+
+        /// Whether equal elements always keep their relative order.
+        fn is_stable(&self) -> bool;
+
+        /// Whether the algorithm sorts without allocating memory proportional to the input.
+        /// Recursion of O(log n) depth is allowed.
+        fn is_in_place(&self) -> bool;
+
+        /// The number of comparisons the algorithm makes in the worst case.
+        fn worst_case(&self) -> Complexity;
+
+        /// The number of comparisons the algorithm makes on average, over random inputs.
+        fn average_case(&self) -> Complexity;
+
+        /// Sorts a slice with a comparator function.
+        fn sort_by<T, F>(&self, arr: &mut [T], compare: F)
+        where
+            Self: Sized,
+            F: FnMut(&T, &T) -> Ordering;
+
+        /// Sorts a slice.
+        fn sort<T: Ord>(&self, arr: &mut [T])
+        where
+            Self: Sized,
+        {
+            self.sort_by(arr, T::cmp);
+        }
+
+        /// Sorts a slice with a key extraction function.
+        fn sort_by_key<T, K, F>(&self, arr: &mut [T], mut f: F)
+        where
+            Self: Sized,
+            K: Ord,
+            F: FnMut(&T) -> K,
+        {
+            self.sort_by(arr, |a, b| f(a).cmp(&f(b)));
+        }
+    }
+
+    /// Implements [`Sorter`] for a unit struct by delegating to a sort function.
+    macro_rules! impl_sorter {
+        ($sorter:ident, $sort_by:ident, $name:literal, $stable:literal, $in_place:literal, $worst:ident, $average:ident) => {
+            impl Sorter for $sorter {
+                fn name(&self) -> &'static str {
+                    $name
+                }
+
+                fn is_stable(&self) -> bool {
+                    $stable
+                }
+
+                fn is_in_place(&self) -> bool {
+                    $in_place
+                }
+
+                fn worst_case(&self) -> Complexity {
+                    Complexity::$worst
+                }
+
+                fn average_case(&self) -> Complexity {
+                    Complexity::$average
+                }
+
+                fn sort_by<T, F>(&self, arr: &mut [T], compare: F)
+                where
+                    F: FnMut(&T, &T) -> Ordering,
+                {
+                    $sort_by(arr, compare);
+                }
+            }
+        };
+    }
+
+    /// [`merge_sort`](crate::algorithms::sorting::sorting::merge_sort): stable, with an
+    /// O(n) scratch buffer.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct MergeSort;
+
+    /// [`heap_sort`](crate::algorithms::sorting::sorting::heap_sort): in place, not stable.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct HeapSort;
+
+    /// [`quick_sort`](crate::algorithms::sorting::sorting::quick_sort): in place, not stable,
+    /// and falls back to heap sort to stay O(n log n) in the worst case.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct QuickSort;
+
+    /// [`insertion_sort`](crate::algorithms::sorting::sorting::insertion_sort): stable and in
+    /// place, and linear on nearly sorted input.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct InsertionSort;
+
+    /// [`selection_sort`](crate::algorithms::sorting::sorting::selection_sort): in place, not
+    /// stable.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct SelectionSort;
+
+    /// [`bubble_sort`](crate::algorithms::sorting::sorting::bubble_sort): stable and in place.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct BubbleSort;
+
+    /// [`shell_sort`](crate::algorithms::sorting::sorting::shell_sort): in place, not stable,
+    /// and much faster than the other quadratic sorts in practice. The complexities describe
+    /// its default Ciura gaps: no pass is worse than quadratic, and on average it is
+    /// subquadratic, though no tight bound is known.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct ShellSort;
+
+    /// [`comb_sort`](crate::algorithms::sorting::sorting::comb_sort): in place, not stable.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct CombSort;
+
+    /// [`cocktail_shaker_sort`](crate::algorithms::sorting::sorting::cocktail_shaker_sort):
+    /// stable and in place.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct CocktailShakerSort;
+
+    /// [`gnome_sort`](crate::algorithms::sorting::sorting::gnome_sort): stable and in place.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct GnomeSort;
+
+    /// [`cycle_sort`](crate::algorithms::sorting::sorting::cycle_sort): in place, not stable,
+    /// and moves every element at most once.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct CycleSort;
+
+    /// [`odd_even_sort`](crate::algorithms::sorting::sorting::odd_even_sort): stable and in
+    /// place.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct OddEvenSort;
+
+    impl_sorter!(MergeSort, merge_sort_by, "merge sort", true, false, Linearithmic, Linearithmic);
+    impl_sorter!(HeapSort, heap_sort_by, "heap sort", false, true, Linearithmic, Linearithmic);
+    impl_sorter!(QuickSort, quick_sort_by, "quick sort", false, true, Linearithmic, Linearithmic);
+    impl_sorter!(InsertionSort, insertion_sort_by, "insertion sort", true, true, Quadratic, Quadratic);
+    impl_sorter!(SelectionSort, selection_sort_by, "selection sort", false, true, Quadratic, Quadratic);
+    impl_sorter!(BubbleSort, bubble_sort_by, "bubble sort", true, true, Quadratic, Quadratic);
+    impl_sorter!(ShellSort, shell_sort_by, "shell sort", false, true, Quadratic, Subquadratic);
+    impl_sorter!(CombSort, comb_sort_by, "comb sort", false, true, Quadratic, Quadratic);
+    impl_sorter!(CocktailShakerSort, cocktail_shaker_sort_by, "cocktail shaker sort", true, true, Quadratic, Quadratic);
+    impl_sorter!(GnomeSort, gnome_sort_by, "gnome sort", true, true, Quadratic, Quadratic);
+    impl_sorter!(CycleSort, cycle_sort_by, "cycle sort", false, true, Quadratic, Quadratic);
+    impl_sorter!(OddEvenSort, odd_even_sort_by, "odd-even sort", true, true, Quadratic, Quadratic);
+
+    /// Sorts a slice, guaranteeing that equal elements keep their relative order.
+    ///
+    /// This is the entry point to use whenever stability matters. It is currently merge sort,
+    /// but may change to any other stable O(n log n) algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorter::sorter::stable_sort;
+    ///
+    /// let mut arr = [3, 1, 2];
+    /// stable_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn stable_sort<T: Ord>(arr: &mut [T]) {
+        stable_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice with a comparator function, guaranteeing that elements the comparator
+    /// considers equal keep their relative order. See [`stable_sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorter::sorter::stable_sort_by;
+    ///
+    /// let mut arr = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    /// stable_sort_by(&mut arr, |a, b| a.0.cmp(&b.0));
+    /// assert_eq!(arr, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    /// ```
+    pub fn stable_sort_by<T, F>(arr: &mut [T], compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        MergeSort.sort_by(arr, compare);
+    }
+
+    /// Sorts a slice with a key extraction function, guaranteeing that elements with equal
+    /// keys keep their relative order. See [`stable_sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorter::sorter::stable_sort_by_key;
+    ///
+    /// let mut arr = ["bb", "a", "cc", "d"];
+    /// stable_sort_by_key(&mut arr, |s| s.len());
+    /// assert_eq!(arr, ["a", "d", "bb", "cc"]);
+    /// ```
+    pub fn stable_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        stable_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+}
@@ -3,7 +3,117 @@
 //! `sorting` is a library for common sorting algorithms implemented in rust.
 
 pub mod sorting {
+    use std::cmp::Ordering;
+    use std::marker::PhantomData;
+    use std::ptr;
+
+    use crate::algorithms::networks::networks::network_sort_impl;
+
+    /// Receives a callback for every basic operation a sort performs, so that the same
+    /// implementation of each algorithm serves the plain, instrumented and traced entry points.
+    /// Indices are positions in the slice that was passed to the entry point.
+    pub(crate) trait SortObserver<T> {
+        /// The elements at `i` and `j` were compared.
+        fn compare(&mut self, _i: usize, _j: usize) {}
+
+        /// The elements at `i` and `j` were swapped.
+        fn swap(&mut self, _i: usize, _j: usize) {}
+
+        /// `value` was moved into position `index`, other than by a swap.
+        fn write(&mut self, _index: usize, _value: &T) {}
+
+        /// A recursive call `depth` levels below the entry point started.
+        fn enter(&mut self, _depth: usize) {}
+
+        /// The range `start..end` was partitioned, with the pivot ending up at `pivot`.
+        fn partition(&mut self, _start: usize, _end: usize, _pivot: usize) {}
+
+        /// The max-heap being sorted now occupies `start..end`.
+        fn heap_boundary(&mut self, _start: usize, _end: usize) {}
+    }
+
+    /// An observer that ignores everything, used by the plain sorting functions.
+    pub(crate) struct NoObserver;
+
+    impl<T> SortObserver<T> for NoObserver {}
+
+    /// The comparator and observer of a running sort, along with where the subslice currently
+    /// being worked on starts in the full slice and how deeply nested the current call is.
+    pub(crate) struct SortOps<'a, T, F, O> {
+        compare: &'a mut F,
+        observer: &'a mut O,
+        offset: usize,
+        depth: usize,
+        marker: PhantomData<fn(&T)>,
+    }
+
+    impl<'a, T, F, O> SortOps<'a, T, F, O>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        pub(crate) fn new(compare: &'a mut F, observer: &'a mut O) -> Self {
+            SortOps { compare, observer, offset: 0, depth: 0, marker: PhantomData }
+        }
+
+        /// Operations on the subslice starting at `offset` within the current subslice.
+        pub(crate) fn at(&mut self, offset: usize) -> SortOps<'_, T, F, O> {
+            SortOps {
+                compare: &mut *self.compare,
+                observer: &mut *self.observer,
+                offset: self.offset + offset,
+                depth: self.depth,
+                marker: PhantomData,
+            }
+        }
+
+        /// Like `at`, for a recursive call one level deeper than the current one.
+        pub(crate) fn nested(&mut self, offset: usize) -> SortOps<'_, T, F, O> {
+            self.observer.enter(self.depth + 1);
+            let mut ops = self.at(offset);
+            ops.depth += 1;
+            ops
+        }
+
+        /// Compares `arr[i]` with `arr[j]`.
+        pub(crate) fn compare(&mut self, arr: &[T], i: usize, j: usize) -> Ordering {
+            self.observer.compare(self.offset + i, self.offset + j);
+            (self.compare)(&arr[i], &arr[j])
+        }
+
+        /// Compares two elements that have been moved out of the slice, reporting the
+        /// positions `i` and `j` they logically occupy.
+        pub(crate) fn compare_values(&mut self, a: &T, i: usize, b: &T, j: usize) -> Ordering {
+            self.observer.compare(self.offset + i, self.offset + j);
+            (self.compare)(a, b)
+        }
+
+        /// Swaps `arr[i]` with `arr[j]`.
+        pub(crate) fn swap(&mut self, arr: &mut [T], i: usize, j: usize) {
+            self.observer.swap(self.offset + i, self.offset + j);
+            arr.swap(i, j);
+        }
+
+        /// Reports that `value` has just been moved into position `index`.
+        pub(crate) fn wrote(&mut self, index: usize, value: &T) {
+            self.observer.write(self.offset + index, value);
+        }
+
+        /// Reports that `start..end` has been partitioned around the pivot now at `pivot`.
+        pub(crate) fn partitioned(&mut self, start: usize, end: usize, pivot: usize) {
+            self.observer.partition(self.offset + start, self.offset + end, self.offset + pivot);
+        }
+
+        /// Reports that the max-heap now occupies `start..end`.
+        pub(crate) fn heap_boundary(&mut self, start: usize, end: usize) {
+            self.observer.heap_boundary(self.offset + start, self.offset + end);
+        }
+    }
+
     /// Sorts a slice using the merge sort algorithm.
+    ///
+    /// The sort is stable and moves elements rather than cloning them. It allocates a single
+    /// scratch buffer of `arr.len() / 2` elements up front and reuses it for every merge.
     /// 
     /// # Examples
     /// 
@@ -14,54 +124,511 @@ pub mod sorting {
     /// merge_sort(&mut arr);
     /// assert_eq!(arr, [1, 2, 3]);
     /// ```
-    
-    pub fn merge_sort<T: Ord + Clone>(arr: &mut [T]) {
+    pub fn merge_sort<T: Ord>(arr: &mut [T]) {
+        merge_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the merge sort algorithm with a comparator function.
+    ///
+    /// The comparator must define a total order for the elements in the slice. Elements that
+    /// compare as `Ordering::Equal` keep their original relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::merge_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// merge_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn merge_sort_by<T, F>(arr: &mut [T], compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut buf = Vec::new();
+        merge_sort_with_buffer_by(arr, &mut buf, compare);
+    }
+
+    /// Sorts a slice using the merge sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::merge_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// merge_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn merge_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        merge_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts a slice using the merge sort algorithm, using `buf` as scratch space.
+    ///
+    /// Any elements in `buf` are dropped first. Its capacity is grown to at least
+    /// `arr.len() / 2` if needed, and it is left empty afterwards, so the same buffer can be
+    /// passed to many calls without allocating again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::merge_sort_with_buffer;
+    ///
+    /// let mut buf = Vec::new();
+    /// for mut arr in [vec![3, 2, 1], vec![6, 4, 5]] {
+    ///     merge_sort_with_buffer(&mut arr, &mut buf);
+    ///     assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    /// }
+    /// assert!(buf.is_empty());
+    /// ```
+    pub fn merge_sort_with_buffer<T: Ord>(arr: &mut [T], buf: &mut Vec<T>) {
+        merge_sort_with_buffer_by(arr, buf, T::cmp);
+    }
+
+    /// Sorts a slice using the merge sort algorithm with a comparator function, using `buf` as
+    /// scratch space.
+    ///
+    /// See [`merge_sort_with_buffer`] for how the buffer is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::merge_sort_with_buffer_by;
+    ///
+    /// let mut buf = Vec::new();
+    /// let mut arr = [1, 2, 3];
+    /// merge_sort_with_buffer_by(&mut arr, &mut buf, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn merge_sort_with_buffer_by<T, F>(arr: &mut [T], buf: &mut Vec<T>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        merge_sort_impl(arr, buf, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using an iterative, bottom-up merge sort.
+    ///
+    /// Instead of recursing, it merges runs of width 1, 2, 4, ... until the whole slice is one
+    /// run. Like [`merge_sort`] it is stable and allocates a single scratch buffer of
+    /// `arr.len() / 2` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::bottom_up_merge_sort;
+    ///
+    /// let mut arr = [5, 3, 4, 1, 2];
+    /// bottom_up_merge_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn bottom_up_merge_sort<T: Ord>(arr: &mut [T]) {
+        bottom_up_merge_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using an iterative, bottom-up merge sort with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::bottom_up_merge_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// bottom_up_merge_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn bottom_up_merge_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let mut buf = Vec::with_capacity(len / 2);
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut compare, &mut observer);
+
+        let mut width = 1;
+        while width < len {
+            // Merge each pair of neighbouring runs; a trailing lone run is left as it is
+            let mut start = 0;
+            while start + width < len {
+                let end = (start + 2 * width).min(len);
+                merge_runs(&mut arr[start..end], width, &mut buf, &mut ops.at(start));
+                start = end;
+            }
+            width *= 2;
+        }
+    }
+
+    /// Sorts a slice using a merge sort that needs no scratch buffer.
+    ///
+    /// Runs are merged in place by rotating them into each other, which takes O(n log n)
+    /// comparisons but O(n log^2 n) moves, so it is slower than [`merge_sort`] but allocates
+    /// nothing. The sort is stable, and its recursion is O(log n) deep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::in_place_merge_sort;
+    ///
+    /// let mut arr = [5, 3, 4, 1, 2];
+    /// in_place_merge_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn in_place_merge_sort<T: Ord>(arr: &mut [T]) {
+        in_place_merge_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using a merge sort that needs no scratch buffer, with a comparator
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::in_place_merge_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// in_place_merge_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn in_place_merge_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        in_place_merge_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using a merge sort that needs no scratch buffer, with a key extraction
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::in_place_merge_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// in_place_merge_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn in_place_merge_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        in_place_merge_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn in_place_merge_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        if len <= SMALL_SORT_THRESHOLD {
+            insertion_sort_impl(arr, ops);
+            return;
+        }
+
+        let mid = len / 2;
+        in_place_merge_sort_impl(&mut arr[..mid], &mut ops.nested(0));
+        in_place_merge_sort_impl(&mut arr[mid..], &mut ops.nested(mid));
+        merge_runs_in_place(arr, mid, ops);
+    }
+
+    /// Merges the sorted runs `arr[..mid]` and `arr[mid..]` into one sorted run without a
+    /// buffer.
+    ///
+    /// The longer run is split at its middle element, and the other run at where that element
+    /// belongs in it. Rotating the two inner pieces past each other leaves two smaller merges
+    /// on either side, each at most three quarters as long as this one.
+    fn merge_runs_in_place<T, F, O>(arr: &mut [T], mid: usize, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        if mid == 0 || mid == len {
+            return;
+        }
+
+        // The runs are already in order, nothing to merge
+        if ops.compare(arr, mid - 1, mid) != Ordering::Greater {
+            return;
+        }
+        if len == 2 {
+            ops.swap(arr, 0, 1);
+            return;
+        }
+
+        let (left_cut, right_cut) = if mid >= len - mid {
+            // Elements of the right run equal to the split element stay after it, to be stable
+            let left_cut = mid / 2;
+            let (mut lo, mut hi) = (mid, len);
+            while lo < hi {
+                let probe = lo + (hi - lo) / 2;
+                if ops.compare(arr, probe, left_cut) == Ordering::Less {
+                    lo = probe + 1;
+                } else {
+                    hi = probe;
+                }
+            }
+            (left_cut, lo)
+        } else {
+            // Elements of the left run equal to the split element stay before it, to be stable
+            let right_cut = mid + (len - mid) / 2;
+            let (mut lo, mut hi) = (0, mid);
+            while lo < hi {
+                let probe = lo + (hi - lo) / 2;
+                if ops.compare(arr, probe, right_cut) == Ordering::Greater {
+                    hi = probe;
+                } else {
+                    lo = probe + 1;
+                }
+            }
+            (lo, right_cut)
+        };
+
+        // Swap the left run's tail with the right run's head
+        arr[left_cut..right_cut].rotate_left(mid - left_cut);
+        for (i, value) in arr[left_cut..right_cut].iter().enumerate() {
+            ops.wrote(left_cut + i, value);
+        }
+
+        let new_mid = left_cut + (right_cut - mid);
+        merge_runs_in_place(&mut arr[..new_mid], left_cut, ops);
+        merge_runs_in_place(&mut arr[new_mid..], right_cut - new_mid, &mut ops.at(new_mid));
+    }
+
+    pub(crate) fn merge_sort_impl<T, F, O>(arr: &mut [T], buf: &mut Vec<T>, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        buf.clear();
+        buf.reserve(arr.len() / 2);
+        merge_sort_recursive(arr, buf, ops, None);
+    }
+
+    /// Merge sorts `arr`, finishing off subslices of up to [`SMALL_SORT_THRESHOLD`] elements
+    /// with `small_sort` if there is one.
+    fn merge_sort_recursive<T, F, O>(
+        arr: &mut [T],
+        buf: &mut Vec<T>,
+        ops: &mut SortOps<T, F, O>,
+        small_sort: Option<SmallSort>,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         let len = arr.len();
         
         // Base case: If the slice has 0 or 1 element, it's already sorted
         if len <= 1 {
             return;
         }
+        if let Some(small_sort) = small_sort.filter(|_| len <= SMALL_SORT_THRESHOLD) {
+            small_sort_impl(arr, small_sort, ops);
+            return;
+        }
         
         let mid = len / 2;
-        let mut left = arr[..mid].to_vec(); // Create a copy of the left half
-        let mut right = arr[mid..].to_vec(); // Create a copy of the right half
-    
-        merge_sort(&mut left); // Recursively sort the left half
-        merge_sort(&mut right); // Recursively sort the right half
-    
-        // Merge the sorted halves back into the original slice
-        let mut i = 0;
-        let mut j = 0;
-        let mut k = 0;
-        while i < left.len() && j < right.len() {
-            if left[i] <= right[j] {
-                arr[k] = left[i].clone();
-                i += 1;
+        merge_sort_recursive(&mut arr[..mid], buf, &mut ops.nested(0), small_sort); // Recursively sort the left half
+        merge_sort_recursive(&mut arr[mid..], buf, &mut ops.nested(mid), small_sort); // Recursively sort the right half
+        merge_runs(arr, mid, buf, ops); // Merge the sorted halves in place
+    }
+
+    /// Merges the sorted runs `arr[..mid]` and `arr[mid..]` into one sorted run.
+    ///
+    /// The shorter run is moved into the spare capacity of `buf`, which must be empty and able
+    /// to hold it without reallocating. `buf` is still empty when this returns.
+    pub(crate) fn merge_runs<T, F, O>(arr: &mut [T], mid: usize, buf: &mut Vec<T>, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        if mid == 0 || mid == len {
+            return;
+        }
+
+        // The runs are already in order, nothing to merge
+        if ops.compare(arr, mid - 1, mid) != Ordering::Greater {
+            return;
+        }
+
+        let left_len = mid;
+        let right_len = len - mid;
+        assert!(buf.is_empty() && buf.capacity() >= left_len.min(right_len));
+
+        let base = arr.as_mut_ptr();
+        let scratch = buf.as_mut_ptr();
+
+        // SAFETY: the shorter run is bitwise-moved into the scratch buffer, leaving a gap in
+        // `arr` exactly as long as the number of elements still in the buffer. Every iteration
+        // moves one element into the gap and moves the gap along, and `hole` tracks the
+        // remaining buffer elements and where they belong, so if `compare` panics they are moved
+        // back into the gap and every element ends up in `arr` exactly once. `buf.len()` stays
+        // zero throughout, so the buffer never drops anything itself.
+        unsafe {
+            if left_len <= right_len {
+                // Move the left run out of the way and merge forwards into its place. The next
+                // buffered element logically sits at `out`, at the start of the gap.
+                ptr::copy_nonoverlapping(base, scratch, left_len);
+                let mut hole = MergeHole { src: scratch, dest: base, len: left_len };
+                let mut out = 0;
+                let mut right = mid;
+                while hole.len > 0 && right < len {
+                    // Only take from the right run when it is strictly smaller, to stay stable
+                    if ops.compare_values(&*base.add(right), right, &*hole.src, out) == Ordering::Less {
+                        ptr::copy_nonoverlapping(base.add(right), hole.dest, 1);
+                        right += 1;
+                    } else {
+                        ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+                        hole.src = hole.src.add(1);
+                        hole.len -= 1;
+                    }
+                    out += 1;
+                    hole.dest = base.add(out);
+                    ops.wrote(out - 1, &*base.add(out - 1));
+                }
+
+                // Move whatever is left in the buffer into the remaining gap
+                while hole.len > 0 {
+                    ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+                    hole.src = hole.src.add(1);
+                    hole.len -= 1;
+                    out += 1;
+                    hole.dest = base.add(out);
+                    ops.wrote(out - 1, &*base.add(out - 1));
+                }
             } else {
-                arr[k] = right[j].clone();
-                j += 1;
+                // Move the right run out of the way and merge backwards into its place. The last
+                // buffered element logically sits at `out - 1`, at the end of the gap.
+                ptr::copy_nonoverlapping(base.add(mid), scratch, right_len);
+                let mut hole = MergeHole { src: scratch, dest: base.add(mid), len: right_len };
+                let mut left = mid;
+                let mut out = len;
+                while hole.len > 0 && left > 0 {
+                    // Only take from the left run when it is strictly greater, to stay stable
+                    let last = &*scratch.add(hole.len - 1);
+                    if ops.compare_values(last, out - 1, &*base.add(left - 1), left - 1) == Ordering::Less {
+                        left -= 1;
+                        ptr::copy_nonoverlapping(base.add(left), base.add(out - 1), 1);
+                        hole.dest = base.add(left);
+                    } else {
+                        hole.len -= 1;
+                        ptr::copy_nonoverlapping(scratch.add(hole.len), base.add(out - 1), 1);
+                    }
+                    out -= 1;
+                    ops.wrote(out, &*base.add(out));
+                }
+
+                // Move whatever is left in the buffer into the remaining gap
+                while hole.len > 0 {
+                    hole.len -= 1;
+                    ptr::copy_nonoverlapping(scratch.add(hole.len), base.add(left + hole.len), 1);
+                    ops.wrote(left + hole.len, &*base.add(left + hole.len));
+                }
             }
-            k += 1;
         }
-    
-        // Copy any remaining elements from the left half
-        while i < left.len() {
-            arr[k] = left[i].clone();
-            i += 1;
-            k += 1;
+    }
+
+    /// Elements of a merge that currently live in the scratch buffer, and the gap in the slice
+    /// they are moved into if the hole is dropped before the merge finishes.
+    pub(crate) struct MergeHole<T> {
+        pub(crate) src: *const T,
+        pub(crate) dest: *mut T,
+        pub(crate) len: usize,
+    }
+
+    impl<T> Drop for MergeHole<T> {
+        fn drop(&mut self) {
+            // SAFETY: `src` and `dest` both point at `len` elements that do not overlap
+            unsafe {
+                ptr::copy_nonoverlapping(self.src, self.dest, self.len);
+            }
         }
-    
-        // Copy any remaining elements from the right half
-        while j < right.len() {
-            arr[k] = right[j].clone();
-            j += 1;
-            k += 1;
+    }
+
+    /// How [`merge_sort_with_small_sort`] and [`quick_sort_with_small_sort`] finish off
+    /// subslices of up to 16 elements.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub enum SmallSort {
+        /// Insertion sort, which is stable and quick on nearly sorted subslices.
+        #[default]
+        InsertionSort,
+        /// The sorting network for the subslice's length, which makes the same comparisons
+        /// whatever the order of the elements, so its branches are easy to predict. See
+        /// [`sorting_network`](crate::algorithms::networks::networks::sorting_network). It is
+        /// not stable.
+        SortingNetwork,
+    }
+
+    /// Subslices of at most this length are finished off with a [`SmallSort`]. There is a
+    /// sorting network for every length up to this.
+    const SMALL_SORT_THRESHOLD: usize = 16;
+
+    pub(crate) fn small_sort_impl<T, F, O>(arr: &mut [T], small_sort: SmallSort, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        match small_sort {
+            SmallSort::InsertionSort => insertion_sort_impl(arr, ops),
+            SmallSort::SortingNetwork => network_sort_impl(arr, ops),
         }
     }
 
+    /// Sorts a slice using the merge sort algorithm, finishing off subslices of up to 16
+    /// elements with `small_sort` instead of merging all the way down.
+    ///
+    /// The sort is stable with [`SmallSort::InsertionSort`], but not with
+    /// [`SmallSort::SortingNetwork`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::{merge_sort_with_small_sort, SmallSort};
+    ///
+    /// let mut arr: Vec<u32> = (0..100).rev().collect();
+    /// merge_sort_with_small_sort(&mut arr, SmallSort::SortingNetwork);
+    /// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn merge_sort_with_small_sort<T: Ord>(arr: &mut [T], small_sort: SmallSort) {
+        merge_sort_with_small_sort_by(arr, small_sort, T::cmp);
+    }
+
+    /// Sorts a slice using the merge sort algorithm with a comparator function, finishing off
+    /// subslices of up to 16 elements with `small_sort`. See [`merge_sort_with_small_sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::{merge_sort_with_small_sort_by, SmallSort};
+    ///
+    /// let mut arr = [(1, 'a'), (0, 'b'), (1, 'c')];
+    /// merge_sort_with_small_sort_by(&mut arr, SmallSort::InsertionSort, |a, b| a.0.cmp(&b.0));
+    /// assert_eq!(arr, [(0, 'b'), (1, 'a'), (1, 'c')]);
+    /// ```
+    pub fn merge_sort_with_small_sort_by<T, F>(arr: &mut [T], small_sort: SmallSort, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut buf = Vec::with_capacity(arr.len() / 2);
+        let mut observer = NoObserver;
+        let mut ops = SortOps::new(&mut compare, &mut observer);
+        merge_sort_recursive(arr, &mut buf, &mut ops, Some(small_sort));
+    }
+
     /// Sort a slice using heap sort algorithm.
     /// 
+    /// Takes O(n log n) time and sorts in place. The sort is not stable.
+    /// 
     /// # Examples
     /// 
     /// ```
@@ -71,8 +638,52 @@ pub mod sorting {
     /// heap_sort(&mut arr);
     /// assert_eq!(arr, [1, 2, 3]);
     /// ```
-    
-    pub fn heap_sort<T: Ord>(arr: &mut[T]) {
+    pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+        heap_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the heap sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::heap_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// heap_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn heap_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        heap_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the heap sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::heap_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// heap_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn heap_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        heap_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn heap_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         if arr.len() <= 1 {
             return;
         }
@@ -80,36 +691,49 @@ pub mod sorting {
         // Build heap
         let len = arr.len();
         for i in (0..len / 2).rev() {
-            heapify(arr, len, i);
+            heapify(arr, len, i, &mut ops.nested(0));
         }
+        ops.heap_boundary(0, len);
 
         // Extract elements from heap
         for i in (1..len).rev() {
-            arr.swap(0, i);
-            heapify(arr, i, 0);
+            ops.swap(arr, 0, i);
+            ops.heap_boundary(0, i);
+            heapify(arr, i, 0, &mut ops.nested(0));
         }
     }
 
-    fn heapify<T: Ord>(arr: &mut [T], n: usize, i: usize) {
+    /// Sifts `arr[i]` down into the max-heap formed by the first `n` elements of `arr`.
+    pub(crate) fn heapify<T, F, O>(arr: &mut [T], n: usize, i: usize, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         let mut largest = i;
         let left = 2 * i + 1;
         let right = 2 * i + 2;
 
-        if left < n && arr[left] > arr[largest] {
+        if left < n && ops.compare(arr, left, largest) == Ordering::Greater {
             largest = left;
         }
 
-        if right < n && arr[right] > arr[largest] {
+        if right < n && ops.compare(arr, right, largest) == Ordering::Greater {
             largest = right;
         }
 
         if largest != i {
-            arr.swap(i, largest);
-            heapify(arr, n, largest);
+            ops.swap(arr, i, largest);
+            heapify(arr, n, largest, &mut ops.nested(0));
         }
     }
 
     /// Sorts a slice using the quick sort algorithm.
+    ///
+    /// This is an introsort: pivots are chosen by median-of-three (or Tukey's ninther on long
+    /// slices), only the smaller partition is sorted recursively, subslices of up to 16 elements
+    /// are finished with insertion sort, and if partitioning goes more than about `2 * log2(n)`
+    /// levels deep the remaining subslice is handed to heap sort. This keeps the worst case at
+    /// O(n log n) time and O(log n) stack space. The sort is not stable.
     /// 
     /// # Examples
     /// 
@@ -120,35 +744,359 @@ pub mod sorting {
     /// quick_sort(&mut arr);
     /// assert_eq!(arr, [1, 2, 3]);
     /// ```
-    
     pub fn quick_sort<T: Ord>(arr: &mut [T]) {
-        if arr.len() <= 1 {
-            return;
+        quick_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the quick sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::quick_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// quick_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn quick_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        quick_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the quick sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::quick_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// quick_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn quick_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        quick_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Subslices longer than this pick their pivot with Tukey's ninther instead of a plain
+    /// median-of-three.
+    const NINTHER_THRESHOLD: usize = 128;
+
+    pub(crate) fn quick_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        introsort(arr, ops, depth_limit(arr.len()), false, SmallSort::InsertionSort);
+    }
+
+    /// Sorts a slice using the quick sort algorithm, finishing off subslices of up to 16
+    /// elements with `small_sort`. [`quick_sort`] always uses insertion sort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::{quick_sort_with_small_sort, SmallSort};
+    ///
+    /// let mut arr: Vec<u32> = (0..100).map(|i| i * 37 % 100).collect();
+    /// quick_sort_with_small_sort(&mut arr, SmallSort::SortingNetwork);
+    /// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn quick_sort_with_small_sort<T: Ord>(arr: &mut [T], small_sort: SmallSort) {
+        quick_sort_with_small_sort_by(arr, small_sort, T::cmp);
+    }
+
+    /// Sorts a slice using the quick sort algorithm with a comparator function, finishing off
+    /// subslices of up to 16 elements with `small_sort`. See [`quick_sort_with_small_sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::{quick_sort_with_small_sort_by, SmallSort};
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// quick_sort_with_small_sort_by(&mut arr, SmallSort::SortingNetwork, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn quick_sort_with_small_sort_by<T, F>(arr: &mut [T], small_sort: SmallSort, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let depth_limit = depth_limit(arr.len());
+        introsort(arr, &mut SortOps::new(&mut compare, &mut NoObserver), depth_limit, false, small_sort);
+    }
+
+    /// Allows roughly 2 * log2(n) levels of partitioning before giving up on quick sort.
+    fn depth_limit(len: usize) -> u32 {
+        2 * (usize::BITS - len.leading_zeros())
+    }
+
+    /// Sorts `arr` with quick sort, falling back to heap sort after `depth_limit` levels and
+    /// finishing off short subslices with `small_sort`. With `three_way` set each step uses
+    /// [`partition_3way`] and leaves out every element equal to the pivot, rather than just
+    /// the pivot itself.
+    fn introsort<T, F, O>(
+        mut arr: &mut [T],
+        ops: &mut SortOps<T, F, O>,
+        mut depth_limit: u32,
+        three_way: bool,
+        small_sort: SmallSort,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        // Where the subslice being worked on starts within `ops`' subslice
+        let mut offset = 0;
+        loop {
+            let len = arr.len();
+
+            // Small subslices are faster to finish with a simpler sort
+            if len <= SMALL_SORT_THRESHOLD {
+                small_sort_impl(arr, small_sort, &mut ops.at(offset));
+                return;
+            }
+
+            // Too many bad pivots in a row: fall back to heap sort to stay O(n log n)
+            if depth_limit == 0 {
+                heap_sort_impl(arr, &mut ops.at(offset));
+                return;
+            }
+            depth_limit -= 1;
+
+            // Elements in `equal_start..equal_end` are in their final place
+            let pivot_index = choose_pivot(arr, &mut ops.at(offset));
+            let (equal_start, equal_end) = if three_way {
+                partition_3way(arr, pivot_index, &mut ops.at(offset))
+            } else {
+                let pivot_index = partition(arr, pivot_index, &mut ops.at(offset));
+                (pivot_index, pivot_index + 1)
+            };
+
+            // Recurse into the smaller side and loop on the larger one, so the stack depth
+            // never exceeds O(log n)
+            let (left, right) = std::mem::take(&mut arr).split_at_mut(equal_start);
+            let right = &mut right[equal_end - equal_start..];
+            if left.len() < right.len() {
+                introsort(left, &mut ops.nested(offset), depth_limit, three_way, small_sort);
+                offset += equal_end;
+                arr = right;
+            } else {
+                introsort(right, &mut ops.nested(offset + equal_end), depth_limit, three_way, small_sort);
+                arr = left;
+            }
         }
+    }
 
-        let pivot_index = partition(arr);
-        quick_sort(&mut arr[0..pivot_index]);
-        quick_sort(&mut arr[pivot_index + 1..]);
+    /// Returns the index of a pivot candidate: the median of three samples for short slices,
+    /// or the median of three medians (Tukey's ninther) for long ones.
+    pub(crate) fn choose_pivot<T, F, O>(arr: &[T], ops: &mut SortOps<T, F, O>) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        let mid = len / 2;
+        if len > NINTHER_THRESHOLD {
+            let step = len / 8;
+            let low = median_of_three(arr, 0, step, 2 * step, ops);
+            let middle = median_of_three(arr, mid - step, mid, mid + step, ops);
+            let high = median_of_three(arr, len - 1 - 2 * step, len - 1 - step, len - 1, ops);
+            median_of_three(arr, low, middle, high, ops)
+        } else {
+            median_of_three(arr, 0, mid, len - 1, ops)
+        }
     }
 
-    fn partition<T: Ord>(arr: &mut [T]) -> usize {
+    fn median_of_three<T, F, O>(arr: &[T], a: usize, b: usize, c: usize, ops: &mut SortOps<T, F, O>) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let mut less = |x: usize, y: usize| ops.compare(arr, x, y) == Ordering::Less;
+        if less(a, b) {
+            if less(b, c) {
+                b
+            } else if less(a, c) {
+                c
+            } else {
+                a
+            }
+        } else if less(a, c) {
+            a
+        } else if less(b, c) {
+            c
+        } else {
+            b
+        }
+    }
+
+    /// Partitions the slice around the element at `pivot_index` and returns the pivot's final
+    /// position. Everything before it compares less than or equal to the pivot, everything
+    /// after it compares greater.
+    pub(crate) fn partition<T, F, O>(arr: &mut [T], pivot_index: usize, ops: &mut SortOps<T, F, O>) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         let len = arr.len();
-        let pivot_index = len / 2;
-        arr.swap(pivot_index, len - 1);
+        ops.swap(arr, pivot_index, len - 1);
 
         let mut i = 0;
         for j in 0..len - 1 {
-            if arr[j] <= arr[len - 1] {
-                arr.swap(i, j);
+            if ops.compare(arr, j, len - 1) != Ordering::Greater {
+                ops.swap(arr, i, j);
                 i += 1;
             }
         }
-        arr.swap(i, len - 1);
+        ops.swap(arr, i, len - 1);
+        ops.partitioned(0, len, i);
         i
     }
 
+    /// Partitions the slice three ways around the element at `pivot_index`, and returns the
+    /// range of elements equal to the pivot. Everything before that range compares less than
+    /// the pivot and everything after it compares greater.
+    ///
+    /// This is Bentley and McIlroy's scheme: a Hoare-style scan from both ends that parks
+    /// elements equal to the pivot at the two ends of the slice, and swaps them into the
+    /// middle once the scans meet. When there are no duplicates it costs hardly more than a
+    /// two-way partition.
+    pub(crate) fn partition_3way<T, F, O>(arr: &mut [T], pivot_index: usize, ops: &mut SortOps<T, F, O>) -> (usize, usize)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        if len <= 1 {
+            return (0, len);
+        }
+        ops.swap(arr, pivot_index, 0);
+
+        // arr[1..a] and arr[d + 1..] equal the pivot, arr[a..b] are less and arr[c + 1..=d]
+        // are greater
+        let (mut a, mut b) = (1, 1);
+        let (mut c, mut d) = (len - 1, len - 1);
+        loop {
+            while b <= c {
+                match ops.compare(arr, b, 0) {
+                    Ordering::Greater => break,
+                    Ordering::Equal => {
+                        ops.swap(arr, a, b);
+                        a += 1;
+                    }
+                    Ordering::Less => {}
+                }
+                b += 1;
+            }
+            while c >= b {
+                match ops.compare(arr, c, 0) {
+                    Ordering::Less => break,
+                    Ordering::Equal => {
+                        ops.swap(arr, c, d);
+                        d -= 1;
+                    }
+                    Ordering::Greater => {}
+                }
+                c -= 1;
+            }
+            if b > c {
+                break;
+            }
+            ops.swap(arr, b, c);
+            b += 1;
+            c -= 1;
+        }
+
+        // Swap the equal elements parked at both ends into the middle
+        let less = b - a;
+        let greater = d - c;
+        for i in 0..a.min(less) {
+            ops.swap(arr, i, b - 1 - i);
+        }
+        for i in 0..greater.min(len - 1 - d) {
+            ops.swap(arr, b + i, len - 1 - i);
+        }
+        ops.partitioned(0, len, less);
+        (less, len - greater)
+    }
+
+    /// Sorts a slice using quick sort with a three-way partition.
+    ///
+    /// Works like [`quick_sort`], but every step splits the slice into the elements less than,
+    /// equal to and greater than the pivot, and never looks at the equal ones again. Slices
+    /// with only k distinct values take O(n log k) time, so an all-equal slice is sorted in a
+    /// single linear pass. The sort is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::quick_sort_3way;
+    ///
+    /// let mut arr = [2, 1, 2, 3, 1, 2];
+    /// quick_sort_3way(&mut arr);
+    /// assert_eq!(arr, [1, 1, 2, 2, 2, 3]);
+    /// ```
+    pub fn quick_sort_3way<T: Ord>(arr: &mut [T]) {
+        quick_sort_3way_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using three-way quick sort with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::quick_sort_3way_by;
+    ///
+    /// let mut arr = [1, 3, 1, 2];
+    /// quick_sort_3way_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1, 1]);
+    /// ```
+    pub fn quick_sort_3way_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        quick_sort_3way_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using three-way quick sort with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::quick_sort_3way_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2, 3];
+    /// quick_sort_3way_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr[..2], [1, -2]);
+    /// ```
+    pub fn quick_sort_3way_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        quick_sort_3way_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn quick_sort_3way_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        introsort(arr, ops, depth_limit(arr.len()), true, SmallSort::InsertionSort);
+    }
+
     /// Sorts a slice using the insertion sort algorithm.
     /// 
+    /// Takes O(n^2) time, or O(n + d) on a slice with d inversions, and sorts in place. The
+    /// sort is stable.
+    /// 
     /// # Examples
     /// 
     /// ```
@@ -158,13 +1106,57 @@ pub mod sorting {
     /// insertion_sort(&mut arr);
     /// assert_eq!(arr, [1, 2, 3]);
     /// ```
-    
     pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+        insertion_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the insertion sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::insertion_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// insertion_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn insertion_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        insertion_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the insertion sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::insertion_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// insertion_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn insertion_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        insertion_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn insertion_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         let len = arr.len();
         for i in 1..len {
             let mut j = i;
-            while j > 0 && arr[j - 1] > arr[j] {
-                arr.swap(j - 1, j);
+            while j > 0 && ops.compare(arr, j - 1, j) == Ordering::Greater {
+                ops.swap(arr, j - 1, j);
                 j -= 1;
             }
         }
@@ -172,6 +1164,9 @@ pub mod sorting {
 
     /// Sorts a slice using the selection sort algorithm.
     /// 
+    /// Takes O(n^2) time, with at most n - 1 swaps, and sorts in place. The sort is not stable,
+    /// since a swap can carry an element past others equal to it.
+    /// 
     /// # Examples
     /// 
     /// ```
@@ -181,24 +1176,70 @@ pub mod sorting {
     /// selection_sort(&mut arr);
     /// assert_eq!(arr, [1, 2, 3]);
     /// ```
-    
     pub fn selection_sort<T: Ord>(arr: &mut [T]) {
+        selection_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the selection sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::selection_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// selection_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn selection_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        selection_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the selection sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::selection_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// selection_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn selection_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        selection_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn selection_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         let len = arr.len();
         for i in 0..len {
             let mut min_index = i;
             for j in i + 1..len {
-                if arr[j] < arr[min_index] {
+                if ops.compare(arr, j, min_index) == Ordering::Less {
                     min_index = j;
                 }
             }
             if min_index != i {
-                arr.swap(i, min_index);
+                ops.swap(arr, i, min_index);
             }
         }
     }
 
     /// Sorts a slice using the bubble sort algorithm.
     /// 
+    /// Takes O(n^2) time and sorts in place. The sort is stable.
+    /// 
     /// # Examples
     /// 
     /// ```
@@ -208,15 +1249,653 @@ pub mod sorting {
     /// bubble_sort(&mut arr);
     /// assert_eq!(arr, [1, 2, 3]);
     /// ```
-    
     pub fn bubble_sort<T: Ord>(arr: &mut [T]) {
+        bubble_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the bubble sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::bubble_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// bubble_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn bubble_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        bubble_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the bubble sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::bubble_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// bubble_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn bubble_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        bubble_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn bubble_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
         let len = arr.len();
         for i in 0..len {
             for j in 0..len - 1 - i {
-                if arr[j] > arr[j + 1] {
-                    arr.swap(j, j + 1);
+                if ops.compare(arr, j, j + 1) == Ordering::Greater {
+                    ops.swap(arr, j, j + 1);
+                }
+            }
+        }
+    }
+
+    /// The gap sequence [`shell_sort_with_gaps`] steps through, from the largest gap down to 1.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub enum GapSequence {
+        /// Shell's original sequence n/2, n/4, ..., 1, which takes O(n^2) time in the worst
+        /// case.
+        Shell,
+        /// Knuth's sequence 1, 4, 13, 40, ..., (3^k - 1) / 2, which takes O(n^(3/2)) time in
+        /// the worst case.
+        Knuth,
+        /// Ciura's empirically tuned sequence 1, 4, 10, 23, 57, 132, 301, 701, 1750, extended
+        /// by a factor of 2.25. It has no proven bound, but is the fastest known in practice.
+        #[default]
+        Ciura,
+        /// Sedgewick's sequence 1, 8, 23, 77, 281, ..., 4^k + 3 * 2^(k-1) + 1, which takes
+        /// O(n^(4/3)) time in the worst case.
+        Sedgewick,
+    }
+
+    impl GapSequence {
+        /// Returns the gaps used for a slice of length `len`, largest first. The last gap is
+        /// always 1, unless the slice is too short to need any.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use algorithm_playground::algorithms::sorting::sorting::GapSequence;
+        ///
+        /// assert_eq!(GapSequence::Shell.gaps(20), [10, 5, 2, 1]);
+        /// assert_eq!(GapSequence::Knuth.gaps(20), [13, 4, 1]);
+        /// assert!(GapSequence::Ciura.gaps(1).is_empty());
+        /// ```
+        pub fn gaps(self, len: usize) -> Vec<usize> {
+            let mut gaps = Vec::new();
+            match self {
+                GapSequence::Shell => {
+                    let mut gap = len / 2;
+                    while gap > 0 {
+                        gaps.push(gap);
+                        gap /= 2;
+                    }
+                    return gaps;
+                }
+                GapSequence::Knuth => {
+                    let mut gap = 1;
+                    while gap < len {
+                        gaps.push(gap);
+                        gap = 3 * gap + 1;
+                    }
+                }
+                GapSequence::Ciura => {
+                    const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+                    gaps.extend(CIURA_GAPS.iter().copied().take_while(|&gap| gap < len));
+                    let mut gap = CIURA_GAPS[CIURA_GAPS.len() - 1] * 9 / 4;
+                    while gaps.len() >= CIURA_GAPS.len() && gap < len {
+                        gaps.push(gap);
+                        gap = gap * 9 / 4;
+                    }
+                }
+                GapSequence::Sedgewick => {
+                    if len > 1 {
+                        gaps.push(1);
+                    }
+                    let mut k = 1;
+                    loop {
+                        let gap = (1 << (2 * k)) + 3 * (1 << (k - 1)) + 1;
+                        if gap >= len {
+                            break;
+                        }
+                        gaps.push(gap);
+                        k += 1;
+                    }
+                }
+            }
+            gaps.reverse();
+            gaps
+        }
+    }
+
+    /// Sorts a slice using the shell sort algorithm with Ciura's gap sequence.
+    ///
+    /// Shell sort insertion sorts the elements that lie a gap apart, for a shrinking sequence
+    /// of gaps ending in 1. The early passes move elements long distances cheaply, so that the
+    /// final plain insertion sort has little left to do. See [`GapSequence`] for the running
+    /// time. Sorts in place. The sort is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::shell_sort;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// shell_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn shell_sort<T: Ord>(arr: &mut [T]) {
+        shell_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the shell sort algorithm with Ciura's gap sequence and a comparator
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::shell_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// shell_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn shell_sort_by<T, F>(arr: &mut [T], compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        shell_sort_with_gaps_by(arr, GapSequence::default(), compare);
+    }
+
+    /// Sorts a slice using the shell sort algorithm with Ciura's gap sequence and a key
+    /// extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::shell_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// shell_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn shell_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        shell_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts a slice using the shell sort algorithm with the given gap sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::{shell_sort_with_gaps, GapSequence};
+    ///
+    /// let mut arr = [5, 1, 4, 2, 3];
+    /// shell_sort_with_gaps(&mut arr, GapSequence::Knuth);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn shell_sort_with_gaps<T: Ord>(arr: &mut [T], gaps: GapSequence) {
+        shell_sort_with_gaps_by(arr, gaps, T::cmp);
+    }
+
+    /// Sorts a slice using the shell sort algorithm with the given gap sequence and a
+    /// comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::{shell_sort_with_gaps_by, GapSequence};
+    ///
+    /// let mut arr = [1, 2, 3, 4];
+    /// shell_sort_with_gaps_by(&mut arr, GapSequence::Sedgewick, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [4, 3, 2, 1]);
+    /// ```
+    pub fn shell_sort_with_gaps_by<T, F>(arr: &mut [T], gaps: GapSequence, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        shell_sort_impl(arr, gaps, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    pub(crate) fn shell_sort_impl<T, F, O>(arr: &mut [T], gaps: GapSequence, ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        for gap in gaps.gaps(len) {
+            for i in gap..len {
+                let mut j = i;
+                while j >= gap && ops.compare(arr, j - gap, j) == Ordering::Greater {
+                    ops.swap(arr, j - gap, j);
+                    j -= gap;
+                }
+            }
+        }
+    }
+
+    /// Sorts a slice using the comb sort algorithm.
+    ///
+    /// Comb sort is bubble sort with a gap: each pass compares and swaps elements a gap apart,
+    /// and the gap shrinks by a factor of 1.3 between passes until it reaches 1. Small elements
+    /// near the end, which bubble sort moves one place per pass, are carried forward quickly.
+    /// Takes O(n^2) time in the worst case but far less in practice, and sorts in place. The
+    /// sort is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::comb_sort;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// comb_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn comb_sort<T: Ord>(arr: &mut [T]) {
+        comb_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the comb sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::comb_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// comb_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn comb_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        comb_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the comb sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::comb_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// comb_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn comb_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        comb_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn comb_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        let mut gap = len;
+        let mut swapped = true;
+        // Once the gap is down to 1 this is bubble sort, which stops after a pass without swaps
+        while gap > 1 || swapped {
+            gap = (gap * 10 / 13).max(1);
+            swapped = false;
+            for i in 0..len.saturating_sub(gap) {
+                if ops.compare(arr, i, i + gap) == Ordering::Greater {
+                    ops.swap(arr, i, i + gap);
+                    swapped = true;
+                }
+            }
+        }
+    }
+
+    /// Sorts a slice using the cocktail shaker sort algorithm.
+    ///
+    /// Cocktail shaker sort is bubble sort that alternates between forward passes, which carry
+    /// the largest element to the end, and backward passes, which carry the smallest to the
+    /// front. Each pass only covers the range between the last swaps of the previous passes.
+    /// Takes O(n^2) time, or O(n) on a sorted slice, and sorts in place. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::cocktail_shaker_sort;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// cocktail_shaker_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn cocktail_shaker_sort<T: Ord>(arr: &mut [T]) {
+        cocktail_shaker_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the cocktail shaker sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::cocktail_shaker_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// cocktail_shaker_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn cocktail_shaker_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        cocktail_shaker_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the cocktail shaker sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::cocktail_shaker_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// cocktail_shaker_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn cocktail_shaker_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        cocktail_shaker_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn cocktail_shaker_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        // Everything outside start..end is already in its final place
+        let mut start = 0;
+        let mut end = arr.len();
+        while end - start > 1 {
+            let mut last_swap = start;
+            for i in start..end - 1 {
+                if ops.compare(arr, i, i + 1) == Ordering::Greater {
+                    ops.swap(arr, i, i + 1);
+                    last_swap = i + 1;
+                }
+            }
+            end = last_swap;
+            if end <= start + 1 {
+                break;
+            }
+
+            last_swap = end;
+            for i in (start..end - 1).rev() {
+                if ops.compare(arr, i, i + 1) == Ordering::Greater {
+                    ops.swap(arr, i, i + 1);
+                    last_swap = i;
+                }
+            }
+            start = last_swap;
+        }
+    }
+
+    /// Sorts a slice using the gnome sort algorithm.
+    ///
+    /// Gnome sort walks forward while neighbouring elements are in order, and whenever they
+    /// are not, swaps them and steps back, carrying the smaller element back until it fits.
+    /// It is insertion sort done with a single index. Takes O(n^2) time, or O(n) on a sorted
+    /// slice, and sorts in place. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::gnome_sort;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// gnome_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn gnome_sort<T: Ord>(arr: &mut [T]) {
+        gnome_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the gnome sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::gnome_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// gnome_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn gnome_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        gnome_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the gnome sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::gnome_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// gnome_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn gnome_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        gnome_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn gnome_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let mut i = 1;
+        while i < arr.len() {
+            if i == 0 || ops.compare(arr, i - 1, i) != Ordering::Greater {
+                i += 1;
+            } else {
+                ops.swap(arr, i - 1, i);
+                i -= 1;
+            }
+        }
+    }
+
+    /// Sorts a slice using the cycle sort algorithm.
+    ///
+    /// Cycle sort follows each cycle of the permutation that sorts the slice, counting how many
+    /// elements are smaller than an element to find where it belongs and swapping it straight
+    /// there. Every swap puts an element into its final place, so an element already in place
+    /// is never moved and at most n - 1 swaps are made, the fewest possible. This suits storage
+    /// where writes are expensive. Takes O(n^2) comparisons even on a sorted slice, and sorts in
+    /// place. The sort is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::cycle_sort;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// cycle_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn cycle_sort<T: Ord>(arr: &mut [T]) {
+        cycle_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the cycle sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::cycle_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// cycle_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn cycle_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        cycle_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the cycle sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::cycle_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// cycle_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn cycle_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        cycle_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn cycle_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        for start in 0..len.saturating_sub(1) {
+            // Swap the element at `start` to where it belongs until the one that belongs at
+            // `start` comes back around the cycle
+            loop {
+                let mut pos = start;
+                for i in start + 1..len {
+                    if ops.compare(arr, i, start) == Ordering::Less {
+                        pos += 1;
+                    }
+                }
+                if pos == start {
+                    break;
+                }
+                // Slots already holding an equal element are taken
+                while ops.compare(arr, pos, start) == Ordering::Equal {
+                    pos += 1;
+                }
+                ops.swap(arr, start, pos);
+            }
+        }
+    }
+
+    /// Sorts a slice using the odd-even sort algorithm.
+    ///
+    /// Odd-even sort, or brick sort, alternates between comparing every pair of neighbours
+    /// that starts at an odd index and every pair that starts at an even one, until a round
+    /// of both makes no swaps. The comparisons within a phase are independent of each other,
+    /// which is why it was designed for parallel processors. Takes O(n^2) time, or O(n) on a
+    /// sorted slice, and sorts in place. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::odd_even_sort;
+    ///
+    /// let mut arr = [3, 2, 1];
+    /// odd_even_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    pub fn odd_even_sort<T: Ord>(arr: &mut [T]) {
+        odd_even_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the odd-even sort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::odd_even_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// odd_even_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn odd_even_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        odd_even_sort_impl(arr, &mut SortOps::new(&mut compare, &mut NoObserver));
+    }
+
+    /// Sorts a slice using the odd-even sort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::sorting::sorting::odd_even_sort_by_key;
+    ///
+    /// let mut arr = [-3, 1, -2];
+    /// odd_even_sort_by_key(&mut arr, |x: &i32| x.abs());
+    /// assert_eq!(arr, [1, -2, -3]);
+    /// ```
+    pub fn odd_even_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        odd_even_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    pub(crate) fn odd_even_sort_impl<T, F, O>(arr: &mut [T], ops: &mut SortOps<T, F, O>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+        O: SortObserver<T>,
+    {
+        let len = arr.len();
+        let mut sorted = false;
+        while !sorted {
+            sorted = true;
+            for first in [1, 0] {
+                for i in (first..len.saturating_sub(1)).step_by(2) {
+                    if ops.compare(arr, i, i + 1) == Ordering::Greater {
+                        ops.swap(arr, i, i + 1);
+                        sorted = false;
+                    }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,206 @@
+//! # Strings
+//!
+//! `strings` contains sorting algorithms specialized for byte strings, which look at each byte
+//! of a shared prefix once instead of comparing it again for every pair of strings.
+
+pub mod strings {
+    use std::cmp::Ordering;
+
+    use crate::algorithms::sorting::sorting::insertion_sort_by;
+
+    /// Subslices of at most this length are finished off with insertion sort.
+    const INSERTION_SORT_THRESHOLD: usize = 32;
+
+    /// The number of buckets a byte position splits strings into: one for the strings that
+    /// end before it, and one for each byte value.
+    const BUCKETS: usize = 257;
+
+    /// Returns the bucket of `s` at byte position `depth`: zero if the string is shorter than
+    /// that, and otherwise the byte plus one.
+    fn bucket(s: &[u8], depth: usize) -> usize {
+        s.get(depth).map_or(0, |&b| b as usize + 1)
+    }
+
+    /// Returns how far past `depth` every string in the slice agrees, so that a shared prefix
+    /// can be skipped in one scan instead of one pass per byte.
+    ///
+    /// The scan stops as soon as a string differs from the first at `depth`, so it is cheap
+    /// when there is nothing to skip.
+    fn common_prefix_end<S: AsRef<[u8]>>(arr: &[S], depth: usize) -> usize {
+        let first = &arr[0].as_ref()[depth.min(arr[0].as_ref().len())..];
+        let mut common = first.len();
+        for s in &arr[1..] {
+            let rest = s.as_ref().get(depth..).unwrap_or_default();
+            common = first[..common].iter().zip(rest).take_while(|(a, b)| a == b).count();
+            if common == 0 {
+                break;
+            }
+        }
+        depth + common
+    }
+
+    /// Insertion sorts strings that all share their first `depth` bytes.
+    fn insertion_sort_from<S: AsRef<[u8]>>(arr: &mut [S], depth: usize) {
+        insertion_sort_by(arr, |a, b| a.as_ref()[depth..].cmp(&b.as_ref()[depth..]));
+    }
+
+    /// Sorts a slice of byte strings using multikey quick sort.
+    ///
+    /// This is Bentley and Sedgewick's three-way radix quick sort: each step partitions the
+    /// strings into those whose byte at the current position is less than, equal to or
+    /// greater than a pivot byte, and only the middle part moves on to the next byte. Shared
+    /// prefixes are therefore examined once per string rather than once per comparison, and a
+    /// prefix that every string in a part shares is skipped in a single scan.
+    /// Strings are ordered byte by byte, which for `str` is the same as the usual `Ord`. Only
+    /// the smallest parts are sorted recursively, so the stack stays O(log n) deep. The sort is
+    /// not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::strings::strings::multikey_quick_sort;
+    ///
+    /// let mut arr = ["she", "sells", "seashells", "by", "the", "sea", "shore"];
+    /// multikey_quick_sort(&mut arr);
+    /// assert_eq!(arr, ["by", "sea", "seashells", "sells", "she", "shore", "the"]);
+    /// ```
+    pub fn multikey_quick_sort<S: AsRef<[u8]>>(arr: &mut [S]) {
+        multikey_quick_sort_recursive(arr, 0);
+    }
+
+    fn multikey_quick_sort_recursive<S: AsRef<[u8]>>(mut arr: &mut [S], mut depth: usize) {
+        loop {
+            let len = arr.len();
+            if len <= INSERTION_SORT_THRESHOLD {
+                insertion_sort_from(arr, depth);
+                return;
+            }
+            depth = common_prefix_end(arr, depth);
+
+            let pivot = median_of_three(
+                bucket(arr[0].as_ref(), depth),
+                bucket(arr[len / 2].as_ref(), depth),
+                bucket(arr[len - 1].as_ref(), depth),
+            );
+
+            // Dijkstra's three-way partition: arr[..lt] < pivot, arr[lt..i] == pivot and
+            // arr[gt..] > pivot
+            let (mut lt, mut i, mut gt) = (0, 0, len);
+            while i < gt {
+                match bucket(arr[i].as_ref(), depth).cmp(&pivot) {
+                    Ordering::Less => {
+                        arr.swap(lt, i);
+                        lt += 1;
+                        i += 1;
+                    }
+                    Ordering::Greater => {
+                        gt -= 1;
+                        arr.swap(i, gt);
+                    }
+                    Ordering::Equal => i += 1,
+                }
+            }
+
+            // Strings that ended at this position are all equal, so there is nothing more to do
+            // with the middle part if the pivot was the end of a string
+            let (less, rest) = arr.split_at_mut(lt);
+            let (equal, greater) = rest.split_at_mut(gt - lt);
+            let equal_depth = if pivot == 0 { None } else { Some(depth + 1) };
+
+            // Sort the two smaller parts recursively and loop on the largest one
+            let parts = [(less, Some(depth)), (equal, equal_depth), (greater, Some(depth))];
+            let largest = (0..parts.len()).max_by_key(|&i| parts[i].0.len()).unwrap();
+            let mut next = None;
+            for (index, (part, part_depth)) in parts.into_iter().enumerate() {
+                match part_depth {
+                    Some(part_depth) if index == largest => next = Some((part, part_depth)),
+                    Some(part_depth) => multikey_quick_sort_recursive(part, part_depth),
+                    None => {}
+                }
+            }
+            match next {
+                Some((part, part_depth)) => {
+                    arr = part;
+                    depth = part_depth;
+                }
+                None => return,
+            }
+        }
+    }
+
+    fn median_of_three(a: usize, b: usize, c: usize) -> usize {
+        a.max(b).min(a.min(b).max(c))
+    }
+
+    /// Sorts a slice of byte strings using most-significant-digit radix sort.
+    ///
+    /// Strings are distributed in place into 257 buckets by their byte at the current
+    /// position, with one extra bucket, sorted first, for strings that have already ended.
+    /// Every bucket but that one then moves on to the next byte, skipping straight past any
+    /// prefix all of its strings share. Buckets of up to 32 strings are finished with
+    /// insertion sort, and pending buckets are kept on an explicit stack, so long shared
+    /// prefixes cannot overflow the call stack. Strings are ordered byte by byte,
+    /// which for `str` is the same as the usual `Ord`. The sort is not stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::strings::strings::string_radix_sort;
+    ///
+    /// let mut arr = vec![String::from("/usr/lib"), String::from("/usr"), String::from("/etc")];
+    /// string_radix_sort(&mut arr);
+    /// assert_eq!(arr, ["/etc", "/usr", "/usr/lib"]);
+    /// ```
+    pub fn string_radix_sort<S: AsRef<[u8]>>(arr: &mut [S]) {
+        // The bucket of every string at the byte position its subslice has reached, so that
+        // each pass reads every string only once
+        let mut cache = vec![0u16; arr.len()];
+        // Subslices still to sort, with the byte position they have reached
+        let mut pending = vec![(0, arr.len(), 0)];
+        while let Some((start, end, depth)) = pending.pop() {
+            let arr = &mut arr[start..end];
+            if arr.len() <= INSERTION_SORT_THRESHOLD {
+                insertion_sort_from(arr, depth);
+                continue;
+            }
+            let depth = common_prefix_end(arr, depth);
+
+            let cache = &mut cache[start..end];
+            let mut counts = [0usize; BUCKETS];
+            for (s, cached) in arr.iter().zip(cache.iter_mut()) {
+                *cached = bucket(s.as_ref(), depth) as u16;
+                counts[*cached as usize] += 1;
+            }
+            let mut starts = [0usize; BUCKETS];
+            let mut total = 0;
+            for (bucket_start, &count) in starts.iter_mut().zip(counts.iter()) {
+                *bucket_start = total;
+                total += count;
+            }
+
+            // Swap every string into its bucket, filling the buckets from the front
+            let mut next = starts;
+            for digit in 0..BUCKETS {
+                let bucket_end = starts[digit] + counts[digit];
+                while next[digit] < bucket_end {
+                    let target = cache[next[digit]] as usize;
+                    if target == digit {
+                        next[digit] += 1;
+                    } else {
+                        arr.swap(next[digit], next[target]);
+                        cache.swap(next[digit], next[target]);
+                        next[target] += 1;
+                    }
+                }
+            }
+
+            // Strings in bucket zero have ended and are equal, so only the others go deeper
+            for digit in 1..BUCKETS {
+                if counts[digit] > 1 {
+                    let bucket_start = start + starts[digit];
+                    pending.push((bucket_start, bucket_start + counts[digit], depth + 1));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,494 @@
+//! # TimSort
+//!
+//! `timsort` is an adaptive, stable merge sort that takes advantage of runs that are already
+//! sorted in its input.
+
+pub mod timsort {
+    use std::cmp::Ordering;
+    use std::ptr;
+    use std::slice;
+
+    use crate::algorithms::sorting::sorting::MergeHole;
+
+    /// Slices shorter than this are sorted with binary insertion sort alone. Also bounds the
+    /// minimum run length, which always falls between `MIN_MERGE / 2` and `MIN_MERGE`.
+    const MIN_MERGE: usize = 64;
+
+    /// How many times in a row one run must win a comparison before a merge starts galloping.
+    const MIN_GALLOP: usize = 7;
+
+    /// A sorted run of `len` elements starting at `start`.
+    #[derive(Debug, Clone, Copy)]
+    struct Run {
+        start: usize,
+        len: usize,
+    }
+
+    /// State shared by all merges of one sort.
+    struct MergeState<T> {
+        /// Scratch space for the shorter run of a merge. Always empty between merges.
+        buf: Vec<T>,
+        /// The current galloping threshold, adjusted as merges find galloping worthwhile or not.
+        min_gallop: usize,
+    }
+
+    /// Sorts a slice using the TimSort algorithm.
+    ///
+    /// TimSort splits the slice into natural runs: stretches that are already non-descending,
+    /// or strictly descending and reversed in place. Runs shorter than a minimum length
+    /// (between 32 and 64, chosen from the slice length) are extended with binary insertion
+    /// sort. The runs are kept on a stack whose lengths shrink faster than the Fibonacci numbers,
+    /// and neighbouring runs are merged with a galloping merge that copies whole blocks once
+    /// one run keeps winning. Already sorted or reversed input takes n - 1 comparisons, and the
+    /// worst case is O(n log n). The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::timsort::timsort::tim_sort;
+    ///
+    /// let mut arr = [1, 2, 3, 7, 8, 9, 6, 5, 4];
+    /// tim_sort(&mut arr);
+    /// assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn tim_sort<T: Ord>(arr: &mut [T]) {
+        tim_sort_by(arr, T::cmp);
+    }
+
+    /// Sorts a slice using the TimSort algorithm with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::timsort::timsort::tim_sort_by;
+    ///
+    /// let mut arr = [1, 2, 3];
+    /// tim_sort_by(&mut arr, |a, b| b.cmp(a));
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    pub fn tim_sort_by<T, F>(arr: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        if len < 2 {
+            return;
+        }
+
+        // Short slices are one run, extended to the whole slice with binary insertion sort
+        if len < MIN_MERGE {
+            let run_len = count_run(arr, &mut compare);
+            binary_insertion_sort(arr, run_len, &mut compare);
+            return;
+        }
+
+        let min_run = min_run_length(len);
+        let mut state = MergeState { buf: Vec::with_capacity(len / 2), min_gallop: MIN_GALLOP };
+        let mut runs = Vec::new();
+
+        let mut start = 0;
+        while start < len {
+            let mut run_len = count_run(&mut arr[start..], &mut compare);
+            if run_len < min_run {
+                let forced = min_run.min(len - start);
+                binary_insertion_sort(&mut arr[start..start + forced], run_len, &mut compare);
+                run_len = forced;
+            }
+
+            runs.push(Run { start, len: run_len });
+            start += run_len;
+            merge_collapse(arr, &mut runs, &mut state, &mut compare);
+        }
+
+        // Merge whatever is left on the stack, smallest neighbours first
+        while runs.len() > 1 {
+            let mut n = runs.len() - 2;
+            if n > 0 && runs[n - 1].len < runs[n + 1].len {
+                n -= 1;
+            }
+            merge_at(arr, &mut runs, n, &mut state, &mut compare);
+        }
+    }
+
+    /// Sorts a slice using the TimSort algorithm with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::timsort::timsort::tim_sort_by_key;
+    ///
+    /// let mut arr = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    /// tim_sort_by_key(&mut arr, |&(key, _)| key);
+    /// assert_eq!(arr, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+    /// ```
+    pub fn tim_sort_by_key<T, K, F>(arr: &mut [T], mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        tim_sort_by(arr, |a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Returns the minimum run length for a slice of length `n`: `n` itself if it is below
+    /// `MIN_MERGE`, otherwise a length between `MIN_MERGE / 2` and `MIN_MERGE` such that
+    /// `n / min_run` is a power of two or slightly less, which keeps the final merges balanced.
+    fn min_run_length(mut n: usize) -> usize {
+        let mut extra = 0;
+        while n >= MIN_MERGE {
+            extra |= n & 1;
+            n >>= 1;
+        }
+        n + extra
+    }
+
+    /// Returns the length of the run at the start of `arr`, reversing it first if it is
+    /// strictly descending. Runs that are only non-ascending are not reversed, as that would
+    /// break stability.
+    fn count_run<T, F>(arr: &mut [T], compare: &mut F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        if len < 2 {
+            return len;
+        }
+
+        let mut end = 2;
+        if compare(&arr[1], &arr[0]) == Ordering::Less {
+            while end < len && compare(&arr[end], &arr[end - 1]) == Ordering::Less {
+                end += 1;
+            }
+            arr[..end].reverse();
+        } else {
+            while end < len && compare(&arr[end], &arr[end - 1]) != Ordering::Less {
+                end += 1;
+            }
+        }
+        end
+    }
+
+    /// Sorts `arr`, whose first `sorted` elements are already sorted, by inserting each of the
+    /// remaining elements after the last element not greater than it.
+    fn binary_insertion_sort<T, F>(arr: &mut [T], sorted: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        for i in sorted.max(1)..arr.len() {
+            let (prefix, rest) = arr.split_at(i);
+            let pos = prefix.partition_point(|x| compare(x, &rest[0]) != Ordering::Greater);
+            arr[pos..=i].rotate_right(1);
+        }
+    }
+
+    /// Merges runs until the stack invariants hold again: every run is longer than the run
+    /// after it, and longer than the two runs after it combined.
+    fn merge_collapse<T, F>(arr: &mut [T], runs: &mut Vec<Run>, state: &mut MergeState<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        while runs.len() > 1 {
+            let mut n = runs.len() - 2;
+            // Checking the invariant on the top four runs, not just three, is needed for it
+            // to hold for the whole stack
+            if (n > 0 && runs[n - 1].len <= runs[n].len + runs[n + 1].len)
+                || (n > 1 && runs[n - 2].len <= runs[n - 1].len + runs[n].len)
+            {
+                if runs[n - 1].len < runs[n + 1].len {
+                    n -= 1;
+                }
+            } else if runs[n].len > runs[n + 1].len {
+                break;
+            }
+            merge_at(arr, runs, n, state, compare);
+        }
+    }
+
+    /// Merges the runs at positions `n` and `n + 1` of the stack.
+    fn merge_at<T, F>(arr: &mut [T], runs: &mut Vec<Run>, n: usize, state: &mut MergeState<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let left = runs[n];
+        let right = runs.remove(n + 1);
+        runs[n].len += right.len;
+        let arr = &mut arr[left.start..right.start + right.len];
+
+        // Leading elements of the left run that are not greater than the first element of the
+        // right run are already in place
+        let (left_run, right_run) = arr.split_at(left.len);
+        let skip = gallop(left_run, false, |x| compare(x, &right_run[0]) != Ordering::Greater);
+        let arr = &mut arr[skip..];
+        let mid = left.len - skip;
+        if mid == 0 {
+            return;
+        }
+
+        // Trailing elements of the right run that are not less than the last element of the
+        // left run are already in place
+        let (left_run, right_run) = arr.split_at(mid);
+        let right_len = gallop(right_run, true, |x| compare(x, &left_run[mid - 1]) == Ordering::Less);
+        let arr = &mut arr[..mid + right_len];
+        if right_len == 0 {
+            return;
+        }
+
+        if mid <= right_len {
+            merge_low(arr, mid, state, compare);
+        } else {
+            merge_high(arr, mid, state, compare);
+        }
+    }
+
+    /// Counts the leading elements of `run` that satisfy `pred`, which must hold for some
+    /// prefix of `run` and for nothing after it.
+    ///
+    /// Probes 1, 2, 4, 8, ... elements from the front (or from the back if `from_back` is set)
+    /// to bracket the answer, then binary searches within the bracket, so finding an answer
+    /// `k` elements from the starting end takes O(log k) comparisons.
+    pub(crate) fn gallop<T, P>(run: &[T], from_back: bool, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let len = run.len();
+        let (lo, hi) = if from_back {
+            let mut hi = len;
+            let mut bound = 1;
+            while bound <= len && !pred(&run[len - bound]) {
+                hi = len - bound;
+                bound *= 2;
+            }
+            (if bound <= len { len - bound + 1 } else { 0 }, hi)
+        } else {
+            let mut lo = 0;
+            let mut bound = 1;
+            while bound <= len && pred(&run[bound - 1]) {
+                lo = bound;
+                bound *= 2;
+            }
+            (lo, if bound <= len { bound - 1 } else { len })
+        };
+        lo + run[lo..hi].partition_point(pred)
+    }
+
+    /// Merges `arr[..mid]` and `arr[mid..]` front to back, moving the left run, which must be
+    /// the shorter one, into the scratch buffer.
+    fn merge_low<T, F>(arr: &mut [T], mid: usize, state: &mut MergeState<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let buf = &mut state.buf;
+        assert!(buf.is_empty() && buf.capacity() >= mid);
+
+        let base = arr.as_mut_ptr();
+        let scratch = buf.as_mut_ptr();
+        let mut min_gallop = state.min_gallop;
+
+        // SAFETY: as in `sorting::merge_runs`, the gap between `hole.dest` and `right` is always
+        // exactly `hole.len` elements long, and `hole` moves the buffered elements back into it
+        // if `compare` panics. Blocks of the right run can overlap the gap they are moved into,
+        // so they are moved with `ptr::copy`.
+        unsafe {
+            ptr::copy_nonoverlapping(base, scratch, mid);
+            let mut hole = MergeHole { src: scratch, dest: base, len: mid };
+            let mut right = mid;
+
+            'merge: loop {
+                let mut left_wins = 0;
+                let mut right_wins = 0;
+
+                // Move one element at a time until one run keeps winning
+                loop {
+                    if compare(&*base.add(right), &*hole.src) == Ordering::Less {
+                        ptr::copy_nonoverlapping(base.add(right), hole.dest, 1);
+                        hole.dest = hole.dest.add(1);
+                        right += 1;
+                        right_wins += 1;
+                        left_wins = 0;
+                        if right == len {
+                            break 'merge;
+                        }
+                    } else {
+                        ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+                        hole.src = hole.src.add(1);
+                        hole.dest = hole.dest.add(1);
+                        hole.len -= 1;
+                        left_wins += 1;
+                        right_wins = 0;
+                        if hole.len == 0 {
+                            break 'merge;
+                        }
+                    }
+                    if left_wins >= min_gallop || right_wins >= min_gallop {
+                        break;
+                    }
+                }
+
+                // Gallop: search for how far each run wins and move whole blocks at once
+                min_gallop += 1;
+                loop {
+                    min_gallop -= (min_gallop > 1) as usize;
+
+                    let key = &*base.add(right);
+                    let left_run = slice::from_raw_parts(hole.src, hole.len);
+                    left_wins = gallop(left_run, false, |x| compare(x, key) != Ordering::Greater);
+                    ptr::copy_nonoverlapping(hole.src, hole.dest, left_wins);
+                    hole.src = hole.src.add(left_wins);
+                    hole.dest = hole.dest.add(left_wins);
+                    hole.len -= left_wins;
+                    if hole.len == 0 {
+                        break 'merge;
+                    }
+
+                    // The next element of the left run is greater, so the right one goes next
+                    ptr::copy_nonoverlapping(base.add(right), hole.dest, 1);
+                    hole.dest = hole.dest.add(1);
+                    right += 1;
+                    if right == len {
+                        break 'merge;
+                    }
+
+                    let key = &*hole.src;
+                    let right_run = slice::from_raw_parts(base.add(right), len - right);
+                    right_wins = gallop(right_run, false, |x| compare(x, key) == Ordering::Less);
+                    ptr::copy(base.add(right), hole.dest, right_wins);
+                    hole.dest = hole.dest.add(right_wins);
+                    right += right_wins;
+                    if right == len {
+                        break 'merge;
+                    }
+
+                    // The next element of the right run is not less, so the left one goes next
+                    ptr::copy_nonoverlapping(hole.src, hole.dest, 1);
+                    hole.src = hole.src.add(1);
+                    hole.dest = hole.dest.add(1);
+                    hole.len -= 1;
+                    if hole.len == 0 {
+                        break 'merge;
+                    }
+
+                    if left_wins < MIN_GALLOP && right_wins < MIN_GALLOP {
+                        break;
+                    }
+                }
+                // Galloping stopped paying off, so make it harder to start again
+                min_gallop += 1;
+            }
+            // Dropping `hole` moves whatever is left of the left run into the gap at the end
+        }
+        state.min_gallop = min_gallop.max(1);
+    }
+
+    /// Merges `arr[..mid]` and `arr[mid..]` back to front, moving the right run, which must be
+    /// the shorter one, into the scratch buffer.
+    fn merge_high<T, F>(arr: &mut [T], mid: usize, state: &mut MergeState<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let right_len = len - mid;
+        let buf = &mut state.buf;
+        assert!(buf.is_empty() && buf.capacity() >= right_len);
+
+        let base = arr.as_mut_ptr();
+        let scratch = buf.as_mut_ptr();
+        let mut min_gallop = state.min_gallop;
+
+        // SAFETY: the gap between `left` and `out` is always exactly `hole.len` elements long,
+        // and `hole.dest` is kept equal to `left`, so if `compare` panics the buffered elements
+        // are moved back into the gap. Blocks of the left run can overlap the gap they are
+        // moved into, so they are moved with `ptr::copy`.
+        unsafe {
+            ptr::copy_nonoverlapping(base.add(mid), scratch, right_len);
+            let mut hole = MergeHole { src: scratch, dest: base.add(mid), len: right_len };
+            let mut left = mid;
+            let mut out = len;
+
+            'merge: loop {
+                let mut left_wins = 0;
+                let mut right_wins = 0;
+
+                // Move one element at a time until one run keeps winning
+                loop {
+                    if compare(&*scratch.add(hole.len - 1), &*base.add(left - 1)) == Ordering::Less {
+                        left -= 1;
+                        out -= 1;
+                        ptr::copy_nonoverlapping(base.add(left), base.add(out), 1);
+                        hole.dest = base.add(left);
+                        left_wins += 1;
+                        right_wins = 0;
+                        if left == 0 {
+                            break 'merge;
+                        }
+                    } else {
+                        hole.len -= 1;
+                        out -= 1;
+                        ptr::copy_nonoverlapping(scratch.add(hole.len), base.add(out), 1);
+                        right_wins += 1;
+                        left_wins = 0;
+                        if hole.len == 0 {
+                            break 'merge;
+                        }
+                    }
+                    if left_wins >= min_gallop || right_wins >= min_gallop {
+                        break;
+                    }
+                }
+
+                // Gallop: search for how far each run wins and move whole blocks at once
+                min_gallop += 1;
+                loop {
+                    min_gallop -= (min_gallop > 1) as usize;
+
+                    let key = &*scratch.add(hole.len - 1);
+                    let left_run = slice::from_raw_parts(base, left);
+                    let keep = gallop(left_run, true, |x| compare(x, key) != Ordering::Greater);
+                    left_wins = left - keep;
+                    out -= left_wins;
+                    ptr::copy(base.add(keep), base.add(out), left_wins);
+                    left = keep;
+                    hole.dest = base.add(left);
+                    if left == 0 {
+                        break 'merge;
+                    }
+
+                    // The last element of the left run is not greater, so the right one goes next
+                    hole.len -= 1;
+                    out -= 1;
+                    ptr::copy_nonoverlapping(scratch.add(hole.len), base.add(out), 1);
+                    if hole.len == 0 {
+                        break 'merge;
+                    }
+
+                    let key = &*base.add(left - 1);
+                    let right_run = slice::from_raw_parts(scratch, hole.len);
+                    let keep = gallop(right_run, true, |x| compare(x, key) == Ordering::Less);
+                    right_wins = hole.len - keep;
+                    out -= right_wins;
+                    ptr::copy_nonoverlapping(scratch.add(keep), base.add(out), right_wins);
+                    hole.len = keep;
+                    if hole.len == 0 {
+                        break 'merge;
+                    }
+
+                    // The last element of the right run is less, so the left one goes next
+                    left -= 1;
+                    out -= 1;
+                    ptr::copy_nonoverlapping(base.add(left), base.add(out), 1);
+                    hole.dest = base.add(left);
+                    if left == 0 {
+                        break 'merge;
+                    }
+
+                    if left_wins < MIN_GALLOP && right_wins < MIN_GALLOP {
+                        break;
+                    }
+                }
+                // Galloping stopped paying off, so make it harder to start again
+                min_gallop += 1;
+            }
+            // Dropping `hole` moves whatever is left of the right run into the gap at the start
+        }
+        state.min_gallop = min_gallop.max(1);
+    }
+}
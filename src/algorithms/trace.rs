@@ -0,0 +1,267 @@
+//! # Trace
+//!
+//! `trace` runs the sorting algorithms while recording every step they take, so that a sort
+//! can be replayed one operation at a time, for example to animate it.
+
+pub mod trace {
+    use std::cmp::Ordering;
+
+    use crate::algorithms::sorting::sorting::{
+        bubble_sort_impl, heap_sort_impl, insertion_sort_impl, merge_sort_impl, quick_sort_impl,
+        selection_sort_impl, SortObserver, SortOps,
+    };
+
+    /// A single step of a sort. Indices are positions in the slice being sorted.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum SortEvent<T> {
+        /// The elements at `i` and `j` were compared.
+        Compare { i: usize, j: usize },
+        /// The elements at `i` and `j` were swapped.
+        Swap { i: usize, j: usize },
+        /// `value` was moved into position `index`. Merge sort moves one run out of the slice
+        /// before merging, so until the merge finishes the slice may hold stale copies.
+        Write { index: usize, value: T },
+        /// The range `start..end` was partitioned around the pivot now at `pivot`.
+        Partition { start: usize, end: usize, pivot: usize },
+        /// The max-heap being sorted now occupies `start..end`; everything after it is sorted.
+        HeapBoundary { start: usize, end: usize },
+    }
+
+    impl<T: Clone> SortEvent<T> {
+        /// Applies the event to `arr`. Only swaps and writes change the slice.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use algorithm_playground::algorithms::trace::trace::SortEvent;
+        ///
+        /// let mut arr = [1, 2, 3];
+        /// SortEvent::Swap { i: 0, j: 2 }.apply(&mut arr);
+        /// SortEvent::Write { index: 1, value: 7 }.apply(&mut arr);
+        /// assert_eq!(arr, [3, 7, 1]);
+        /// ```
+        pub fn apply(&self, arr: &mut [T]) {
+            match self {
+                SortEvent::Swap { i, j } => arr.swap(*i, *j),
+                SortEvent::Write { index, value } => arr[*index] = value.clone(),
+                SortEvent::Compare { .. } | SortEvent::Partition { .. } | SortEvent::HeapBoundary { .. } => {}
+            }
+        }
+    }
+
+    /// Replays `events` against a copy of `initial`, returning the state of the slice after
+    /// every event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{bubble_sort_traced, replay};
+    ///
+    /// let initial = [3, 1, 2];
+    /// let events = bubble_sort_traced(&mut initial.clone());
+    /// let states = replay(&initial, &events);
+    /// assert_eq!(states.len(), events.len());
+    /// assert_eq!(states.last().unwrap(), &[1, 2, 3]);
+    /// ```
+    pub fn replay<T: Clone>(initial: &[T], events: &[SortEvent<T>]) -> Vec<Vec<T>> {
+        let mut arr = initial.to_vec();
+        events
+            .iter()
+            .map(|event| {
+                event.apply(&mut arr);
+                arr.clone()
+            })
+            .collect()
+    }
+
+    /// Records every event reported by a sort.
+    struct Recorder<T> {
+        events: Vec<SortEvent<T>>,
+    }
+
+    impl<T: Clone> SortObserver<T> for Recorder<T> {
+        fn compare(&mut self, i: usize, j: usize) {
+            self.events.push(SortEvent::Compare { i, j });
+        }
+
+        fn swap(&mut self, i: usize, j: usize) {
+            self.events.push(SortEvent::Swap { i, j });
+        }
+
+        fn write(&mut self, index: usize, value: &T) {
+            self.events.push(SortEvent::Write { index, value: value.clone() });
+        }
+
+        fn partition(&mut self, start: usize, end: usize, pivot: usize) {
+            self.events.push(SortEvent::Partition { start, end, pivot });
+        }
+
+        fn heap_boundary(&mut self, start: usize, end: usize) {
+            self.events.push(SortEvent::HeapBoundary { start, end });
+        }
+    }
+
+    /// Sorts a slice using merge sort and returns every step it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{merge_sort_traced, SortEvent};
+    ///
+    /// let mut arr = [2, 1];
+    /// let events = merge_sort_traced(&mut arr);
+    /// assert_eq!(events[0], SortEvent::Compare { i: 0, j: 1 });
+    /// assert_eq!(arr, [1, 2]);
+    /// ```
+    pub fn merge_sort_traced<T: Ord + Clone>(arr: &mut [T]) -> Vec<SortEvent<T>> {
+        merge_sort_traced_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using merge sort with a comparator function and returns every step it
+    /// took.
+    pub fn merge_sort_traced_by<T, F>(arr: &mut [T], mut compare: F) -> Vec<SortEvent<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut recorder = Recorder { events: Vec::new() };
+        merge_sort_impl(arr, &mut Vec::new(), &mut SortOps::new(&mut compare, &mut recorder));
+        recorder.events
+    }
+
+    /// Sorts a slice using heap sort and returns every step it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{heap_sort_traced, SortEvent};
+    ///
+    /// let mut arr = [1, 2];
+    /// let events = heap_sort_traced(&mut arr);
+    /// assert!(events.contains(&SortEvent::HeapBoundary { start: 0, end: 1 }));
+    /// assert_eq!(arr, [1, 2]);
+    /// ```
+    pub fn heap_sort_traced<T: Ord + Clone>(arr: &mut [T]) -> Vec<SortEvent<T>> {
+        heap_sort_traced_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using heap sort with a comparator function and returns every step it
+    /// took.
+    pub fn heap_sort_traced_by<T, F>(arr: &mut [T], mut compare: F) -> Vec<SortEvent<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut recorder = Recorder { events: Vec::new() };
+        heap_sort_impl(arr, &mut SortOps::new(&mut compare, &mut recorder));
+        recorder.events
+    }
+
+    /// Sorts a slice using quick sort and returns every step it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{quick_sort_traced, SortEvent};
+    ///
+    /// let mut arr: Vec<u32> = (0..100).rev().collect();
+    /// let events = quick_sort_traced(&mut arr);
+    /// assert!(events.iter().any(|e| matches!(e, SortEvent::Partition { start: 0, end: 100, .. })));
+    /// ```
+    pub fn quick_sort_traced<T: Ord + Clone>(arr: &mut [T]) -> Vec<SortEvent<T>> {
+        quick_sort_traced_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using quick sort with a comparator function and returns every step it
+    /// took.
+    pub fn quick_sort_traced_by<T, F>(arr: &mut [T], mut compare: F) -> Vec<SortEvent<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut recorder = Recorder { events: Vec::new() };
+        quick_sort_impl(arr, &mut SortOps::new(&mut compare, &mut recorder));
+        recorder.events
+    }
+
+    /// Sorts a slice using insertion sort and returns every step it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{insertion_sort_traced, SortEvent};
+    ///
+    /// let mut arr = [2, 1];
+    /// let events = insertion_sort_traced(&mut arr);
+    /// assert_eq!(events, [SortEvent::Compare { i: 0, j: 1 }, SortEvent::Swap { i: 0, j: 1 }]);
+    /// ```
+    pub fn insertion_sort_traced<T: Ord + Clone>(arr: &mut [T]) -> Vec<SortEvent<T>> {
+        insertion_sort_traced_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using insertion sort with a comparator function and returns every step it
+    /// took.
+    pub fn insertion_sort_traced_by<T, F>(arr: &mut [T], mut compare: F) -> Vec<SortEvent<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut recorder = Recorder { events: Vec::new() };
+        insertion_sort_impl(arr, &mut SortOps::new(&mut compare, &mut recorder));
+        recorder.events
+    }
+
+    /// Sorts a slice using selection sort and returns every step it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{selection_sort_traced, SortEvent};
+    ///
+    /// let mut arr = [2, 1];
+    /// let events = selection_sort_traced(&mut arr);
+    /// assert_eq!(events, [SortEvent::Compare { i: 1, j: 0 }, SortEvent::Swap { i: 0, j: 1 }]);
+    /// ```
+    pub fn selection_sort_traced<T: Ord + Clone>(arr: &mut [T]) -> Vec<SortEvent<T>> {
+        selection_sort_traced_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using selection sort with a comparator function and returns every step it
+    /// took.
+    pub fn selection_sort_traced_by<T, F>(arr: &mut [T], mut compare: F) -> Vec<SortEvent<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut recorder = Recorder { events: Vec::new() };
+        selection_sort_impl(arr, &mut SortOps::new(&mut compare, &mut recorder));
+        recorder.events
+    }
+
+    /// Sorts a slice using bubble sort and returns every step it took.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use algorithm_playground::algorithms::trace::trace::{bubble_sort_traced, SortEvent};
+    ///
+    /// let mut arr = [2, 1];
+    /// let events = bubble_sort_traced(&mut arr);
+    /// assert_eq!(events, [SortEvent::Compare { i: 0, j: 1 }, SortEvent::Swap { i: 0, j: 1 }]);
+    /// ```
+    pub fn bubble_sort_traced<T: Ord + Clone>(arr: &mut [T]) -> Vec<SortEvent<T>> {
+        bubble_sort_traced_by(arr, T::cmp)
+    }
+
+    /// Sorts a slice using bubble sort with a comparator function and returns every step it
+    /// took.
+    pub fn bubble_sort_traced_by<T, F>(arr: &mut [T], mut compare: F) -> Vec<SortEvent<T>>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut recorder = Recorder { events: Vec::new() };
+        bubble_sort_impl(arr, &mut SortOps::new(&mut compare, &mut recorder));
+        recorder.events
+    }
+}
@@ -1,13 +1,123 @@
 /// Declare the algorithms module, which contains various searching algorithms.
 pub mod algorithms {
+    #![allow(clippy::module_inception)]
+
     /// Import individual modules from the algorithms directory.
     pub mod searching;
     pub mod sorting;
+    pub mod parallel;
+    pub mod radix;
+    pub mod instrumentation;
+    pub mod trace;
+    pub mod timsort;
+    pub mod selection;
+    pub mod external_sort;
+    pub mod merging;
+    pub mod sortedness;
+    pub mod adaptive;
+    pub mod sorter;
+    pub mod strings;
+    pub mod floats;
+    pub mod networks;
+    pub mod collections;
 }
 
 /// Re-export the public functions from the searching module.
 pub use crate::algorithms::searching::searching::{linear_search, binary_search, Graph, TreeNode};
+pub use crate::algorithms::searching::searching::{
+    find_by, rfind, rfind_by, find_all, find_all_by, count_matches, count_matches_by, linear_search_sentinel,
+    linear_search_chunked, linear_search_iter,
+};
+pub use crate::algorithms::searching::searching::{
+    binary_search_by, binary_search_by_key, lower_bound, lower_bound_by, lower_bound_by_key, upper_bound, upper_bound_by,
+    upper_bound_by_key, equal_range, equal_range_by, equal_range_by_key,
+};
+pub use crate::algorithms::searching::searching::{
+    partition_point, Bisect, bisect, find_root, ternary_search, golden_section_search, Bracket, Tolerance,
+};
+pub use crate::algorithms::searching::searching::{
+    exponential_search, exponential_search_by, exponential_search_by_key, exponential_search_unbounded,
+    interpolation_search, interpolation_search_by_key, InterpolationKey, jump_search, jump_search_by,
+    jump_search_by_key, fibonacci_search, fibonacci_search_by, fibonacci_search_by_key,
+};
 pub use crate::algorithms::sorting::sorting::{merge_sort, heap_sort, quick_sort, insertion_sort, selection_sort, bubble_sort};
+pub use crate::algorithms::sorting::sorting::{
+    merge_sort_with_buffer, merge_sort_with_buffer_by, bottom_up_merge_sort, bottom_up_merge_sort_by,
+    in_place_merge_sort, in_place_merge_sort_by, in_place_merge_sort_by_key,
+};
+pub use crate::algorithms::sorting::sorting::{
+    merge_sort_by, heap_sort_by, quick_sort_by, insertion_sort_by, selection_sort_by, bubble_sort_by,
+    merge_sort_by_key, heap_sort_by_key, quick_sort_by_key, insertion_sort_by_key, selection_sort_by_key, bubble_sort_by_key,
+};
+pub use crate::algorithms::sorting::sorting::{quick_sort_3way, quick_sort_3way_by, quick_sort_3way_by_key};
+pub use crate::algorithms::sorting::sorting::{
+    shell_sort, comb_sort, cocktail_shaker_sort, gnome_sort, cycle_sort, odd_even_sort, shell_sort_by, comb_sort_by,
+    cocktail_shaker_sort_by, gnome_sort_by, cycle_sort_by, odd_even_sort_by, shell_sort_by_key, comb_sort_by_key,
+    cocktail_shaker_sort_by_key, gnome_sort_by_key, cycle_sort_by_key, odd_even_sort_by_key, shell_sort_with_gaps,
+    shell_sort_with_gaps_by, GapSequence,
+};
+pub use crate::algorithms::sorting::sorting::{
+    merge_sort_with_small_sort, merge_sort_with_small_sort_by, quick_sort_with_small_sort, quick_sort_with_small_sort_by,
+    SmallSort,
+};
+pub use crate::algorithms::parallel::parallel::{par_merge_sort, par_merge_sort_by, par_quick_sort, par_quick_sort_by, ParallelConfig};
+pub use crate::algorithms::radix::radix::{radix_sort, radix_sort_by_key, msd_radix_sort, counting_sort, RadixKey};
+pub use crate::algorithms::instrumentation::instrumentation::{
+    merge_sort_instrumented, heap_sort_instrumented, quick_sort_instrumented, insertion_sort_instrumented,
+    selection_sort_instrumented, bubble_sort_instrumented, SortStats,
+};
+pub use crate::algorithms::trace::trace::{
+    merge_sort_traced, heap_sort_traced, quick_sort_traced, insertion_sort_traced, selection_sort_traced,
+    bubble_sort_traced, replay, SortEvent,
+};
+pub use crate::algorithms::timsort::timsort::{tim_sort, tim_sort_by, tim_sort_by_key};
+pub use crate::algorithms::selection::selection::{
+    select_nth, select_nth_by, select_nth_by_key, median, percentile, partial_sort, partial_sort_by, partial_sort_by_key,
+    top_k, top_k_by, top_k_by_key,
+};
+pub use crate::algorithms::external_sort::external_sort::{
+    external_sort, external_sort_by, ExternalSortConfig, ExternalSortReport, FixedWidthCodec, LineCodec, RecordCodec,
+};
+pub use crate::algorithms::merging::merging::{
+    merge, merge_by, merge_by_key, kmerge, kmerge_by, KMerge, union, union_by, intersection, intersection_by, difference,
+    difference_by, symmetric_difference, symmetric_difference_by,
+};
+pub use crate::algorithms::sortedness::sortedness::{
+    is_sorted, is_sorted_by, is_sorted_by_key, longest_sorted_run, longest_sorted_run_by, count_inversions,
+    count_inversions_by, sortedness_report, sortedness_report_by, SortednessReport,
+};
+pub use crate::algorithms::adaptive::adaptive::{smart_sort, smart_sort_by, smart_sort_by_key, SortOptions, SortStrategy};
+pub use crate::algorithms::sorter::sorter::{
+    stable_sort, stable_sort_by, stable_sort_by_key, Sorter, Complexity, MergeSort, HeapSort, QuickSort, InsertionSort,
+    SelectionSort, BubbleSort, ShellSort, CombSort, CocktailShakerSort, GnomeSort, CycleSort, OddEvenSort,
+};
+pub use crate::algorithms::strings::strings::{multikey_quick_sort, string_radix_sort};
+pub use crate::algorithms::floats::floats::{
+    compare_floats, sort_floats, sort_floats_with, sort_by_float_key, sort_by_float_key_with, radix_sort_floats, Float,
+    NanPlacement,
+};
+pub use crate::algorithms::networks::networks::{
+    network_sort, network_sort_by, network_sort_by_key, bitonic_sort, bitonic_sort_by, bitonic_sort_by_key,
+    sorting_network, bitonic_network, apply_network, apply_network_by, verify_network, Comparator, MAX_NETWORK_LEN,
+};
+pub use crate::algorithms::collections::collections::{
+    sort_linked_list, sort_linked_list_by, sort_linked_list_by_key, sort_vec_deque, sort_vec_deque_by,
+    sort_vec_deque_by_key,
+};
+
+/// Generates `len` pseudo-random numbers below `modulus` with a xorshift generator, for tests.
+#[cfg(test)]
+fn pseudo_random(len: usize, modulus: u64, seed: u64) -> Vec<u64> {
+    let mut state = seed | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % modulus
+        })
+        .collect()
+}
 
 /// Module containing unit tests for the searching algorithms.
 #[cfg(test)]
@@ -51,15 +161,15 @@ mod search_tests {
     fn test_binary_search() {
         // Test with integers
         let arr_int = vec![1, 2, 3, 4, 5];
-        assert_eq!(binary_search(&arr_int, &3), Some(2));
+        assert_eq!(binary_search(&arr_int, &3), Ok(2));
     
         // Test with strings
         let arr_str = vec!["apple", "banana", "cherry", "date"];
-        assert_eq!(binary_search(&arr_str, &"cherry"), Some(2));
+        assert_eq!(binary_search(&arr_str, &"cherry"), Ok(2));
 
         // Test with characters
         let arr_char = vec!['a', 'b', 'c', 'd', 'e'];
-        assert_eq!(binary_search(&arr_char, &'c'), Some(2)); 
+        assert_eq!(binary_search(&arr_char, &'c'), Ok(2)); 
 
         // Test with custom struct
         #[derive(Debug, PartialEq, Eq, Ord, PartialOrd)]
@@ -73,9 +183,353 @@ mod search_tests {
             Point { x: 2, y: 2 },
             Point { x: 3, y: 3 },
         ];
-        assert_eq!(binary_search(&arr_custom, &Point { x: 3, y: 3 }), Some(2));
+        assert_eq!(binary_search(&arr_custom, &Point { x: 3, y: 3 }), Ok(2));
     } 
 
+    #[test]
+    fn test_binary_search_edge_cases() {
+        // These used to underflow and panic
+        assert_eq!(binary_search::<i32>(&[], &1), Err(0));
+        assert_eq!(binary_search(&[5], &1), Err(0));
+        assert_eq!(binary_search(&[5, 6], &1), Err(0));
+
+        assert_eq!(binary_search(&[5], &5), Ok(0));
+        assert_eq!(binary_search(&[5], &9), Err(1));
+        assert_eq!(binary_search(&[1, 3, 5], &4), Err(2));
+        assert_eq!(binary_search(&[2, 2, 2, 2], &2), Ok(0));
+        assert_eq!(binary_search(&[u64::MAX - 1, u64::MAX], &u64::MAX), Ok(1));
+    }
+
+    #[test]
+    fn test_binary_search_matches_bounds() {
+        for (len, modulus) in [(0, 1), (1, 3), (2, 3), (100, 10), (1000, 50), (1000, 5000)] {
+            let mut arr = pseudo_random(len, modulus, len as u64);
+            arr.sort();
+            for target in 0..modulus + 1 {
+                let lower = arr.iter().filter(|&&x| x < target).count();
+                let upper = arr.iter().filter(|&&x| x <= target).count();
+                assert_eq!(lower_bound(&arr, &target), lower);
+                assert_eq!(upper_bound(&arr, &target), upper);
+                assert_eq!(equal_range(&arr, &target), lower..upper);
+
+                let expected = if lower < upper { Ok(lower) } else { Err(lower) };
+                assert_eq!(binary_search(&arr, &target), expected);
+                assert_eq!(binary_search_by(&arr, |probe| probe.cmp(&target)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_search_by_key() {
+        let mut people = vec![("Eve", 31), ("Bob", 25), ("Ann", 31), ("Dan", 40), ("Cat", 25)];
+        people.sort_by_key(|&(_, age)| age);
+        assert_eq!(binary_search_by_key(&people, &31, |&(_, age)| age), Ok(2));
+        assert_eq!(binary_search_by_key(&people, &30, |&(_, age)| age), Err(2));
+        assert_eq!(lower_bound_by_key(&people, &25, |&(_, age)| age), 0);
+        assert_eq!(upper_bound_by_key(&people, &31, |&(_, age)| age), 4);
+        assert_eq!(equal_range_by_key(&people, &40, |&(_, age)| age), 4..5);
+
+        // Descending order only needs a reversed comparison
+        let arr = [9, 7, 7, 7, 2];
+        assert_eq!(equal_range_by(&arr, |probe| 7.cmp(probe)), 1..4);
+        assert_eq!(lower_bound_by(&arr, |probe| 8.cmp(probe)), 1);
+        assert_eq!(upper_bound_by(&arr, |probe| 1.cmp(probe)), 5);
+    }
+
+    #[test]
+    fn test_linear_search_variants() {
+        let arr = [5, 3, 5, 1, 5, 9];
+        assert_eq!(find_by(&arr, |&x| x < 5), Some(1));
+        assert_eq!(rfind(&arr, &5), Some(4));
+        assert_eq!(rfind_by(&arr, |&x| x < 5), Some(3));
+        assert!(find_all(&arr, &5).eq([0, 2, 4]));
+        assert!(find_all(&arr, &5).rev().eq([4, 2, 0]));
+        assert!(find_all_by(&arr, |&x| x > 5).eq([5]));
+        assert_eq!(count_matches(&arr, &5), 3);
+        assert_eq!(count_matches_by(&arr, |&x| x % 3 == 0), 2);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(find_by(&empty, |_| true), None);
+        assert_eq!(rfind(&empty, &1), None);
+        assert_eq!(find_all(&empty, &1).next(), None);
+        assert_eq!(count_matches(&empty, &1), 0);
+
+        // find_all is lazy, so it stops testing once enough matches are taken
+        let mut tested = 0;
+        let first_two: Vec<usize> = find_all_by(&arr, |&x| {
+            tested += 1;
+            x == 5
+        })
+        .take(2)
+        .collect();
+        assert_eq!(first_two, [0, 2]);
+        assert_eq!(tested, 3);
+    }
+
+    #[test]
+    fn test_fast_linear_searches_agree_with_linear_search() {
+        for len in [0, 1, 2, 15, 16, 17, 33, 100, 1000] {
+            let mut arr = pseudo_random(len, 50, len as u64);
+            let original = arr.clone();
+            for target in 0..52 {
+                let expected = linear_search(&arr, &target);
+                assert_eq!(linear_search_sentinel(&mut arr, &target), expected);
+                assert_eq!(arr, original);
+                assert_eq!(linear_search_chunked(&arr, &target), expected);
+                assert_eq!(linear_search_iter(&arr, &&target), expected);
+                assert_eq!(linear_search_iter(arr.iter().copied(), &target), expected);
+            }
+        }
+
+        // Only the last element matching, and every element matching
+        let mut arr = [0u8; 40];
+        arr[39] = 1;
+        assert_eq!(linear_search_sentinel(&mut arr, &1), Some(39));
+        assert_eq!(linear_search_chunked(&arr, &1), Some(39));
+        assert_eq!(linear_search_sentinel(&mut arr, &0), Some(0));
+        assert_eq!(arr[39], 1);
+
+        // A NaN target never matches, not even its own copy as the sentinel
+        let mut floats = [1.0, f64::NAN, 2.5];
+        assert_eq!(linear_search_sentinel(&mut floats, &f64::NAN), None);
+        assert_eq!(linear_search_sentinel(&mut floats, &2.5), Some(2));
+        assert_eq!(linear_search_sentinel(&mut floats, &1.0), Some(0));
+        assert!(floats[1].is_nan() && floats[2] == 2.5);
+    }
+
+    #[test]
+    fn test_linear_search_iter_stops_at_the_first_match() {
+        use std::collections::{BTreeSet, VecDeque};
+
+        let set: BTreeSet<char> = "search".chars().collect();
+        assert_eq!(linear_search_iter(&set, &&'h'), Some(3));
+        let deque = VecDeque::from([3.5, 1.0, 2.25]);
+        assert_eq!(linear_search_iter(deque, &2.25), Some(2));
+
+        // An endless iterator is fine as long as the target turns up
+        let mut pulled = 0;
+        let position = linear_search_iter(
+            (0u64..).map(|i| {
+                pulled += 1;
+                i * i
+            }),
+            &144,
+        );
+        assert_eq!(position, Some(12));
+        assert_eq!(pulled, 13);
+    }
+
+    #[test]
+    fn test_search_variants_agree_with_binary_search() {
+        for (len, modulus) in [(0, 1), (1, 3), (2, 3), (7, 4), (100, 10), (257, 1000), (1000, u64::MAX)] {
+            let mut arr = pseudo_random(len, modulus, len as u64);
+            arr.sort();
+            let mut targets: Vec<u64> = arr.iter().flat_map(|&x| [x, x.wrapping_add(1), x.wrapping_sub(1)]).collect();
+            targets.extend([0, 1, u64::MAX]);
+            for target in targets {
+                let expected = binary_search(&arr, &target);
+                assert_eq!(exponential_search(&arr, &target), expected, "exponential, {:?}", target);
+                assert_eq!(exponential_search_unbounded(|i| arr.get(i).copied(), &target), expected);
+                assert_eq!(interpolation_search(&arr, &target), expected, "interpolation, {:?}", target);
+                assert_eq!(jump_search(&arr, &target), expected, "jump, {:?}", target);
+                assert_eq!(fibonacci_search(&arr, &target), expected, "fibonacci, {:?}", target);
+            }
+        }
+
+        // Signed keys across zero, and keys that repeat
+        let arr = [-50i32, -50, -3, 0, 0, 0, 7, 7, 1 << 30];
+        for target in -51..10 {
+            assert_eq!(interpolation_search(&arr, &target), binary_search(&arr, &target));
+        }
+
+        // The comparator and key variants search descending and keyed slices
+        let desc = [9, 7, 7, 7, 2];
+        for target in 0..11 {
+            let expected = binary_search_by(&desc, |probe| target.cmp(probe));
+            assert_eq!(exponential_search_by(&desc, |probe| target.cmp(probe)), expected);
+            assert_eq!(jump_search_by(&desc, |probe| target.cmp(probe)), expected);
+            assert_eq!(fibonacci_search_by(&desc, |probe| target.cmp(probe)), expected);
+        }
+        let pairs: Vec<(u32, char)> = (0..40).map(|i| (i / 3 * 5, 'x')).collect();
+        for key in 0..70 {
+            let expected = binary_search_by_key(&pairs, &key, |&(k, _)| k);
+            assert_eq!(exponential_search_by_key(&pairs, &key, |&(k, _)| k), expected);
+            assert_eq!(interpolation_search_by_key(&pairs, &key, |&(k, _)| k), expected);
+            assert_eq!(jump_search_by_key(&pairs, &key, |&(k, _)| k), expected);
+            assert_eq!(fibonacci_search_by_key(&pairs, &key, |&(k, _)| k), expected);
+        }
+    }
+
+    #[test]
+    fn test_search_variant_probe_counts() {
+        use std::cell::Cell;
+
+        let uniform: Vec<u64> = (0..1 << 20).map(|i| i * 7 + 3).collect();
+        let probes = Cell::new(0);
+        let counted = |x: &u64| {
+            probes.set(probes.get() + 1);
+            *x
+        };
+
+        // Exponential search only looks near the front for a target there
+        assert_eq!(exponential_search_by(&uniform, |x| counted(x).cmp(&24)), Ok(3));
+        assert!(probes.take() <= 6);
+
+        // Interpolation search finds keys in uniform data in a handful of probes
+        for target in [3, 7_000_003, 7_340_030] {
+            let expected = binary_search(&uniform, &target);
+            assert_eq!(interpolation_search_by_key(&uniform, &target, counted), expected);
+            assert!(probes.take() <= 12);
+        }
+
+        // On skewed data the guard keeps it logarithmic rather than linear
+        let skewed: Vec<u64> = (0..1 << 16).map(|i| if i + 1 < 1 << 16 { i } else { u64::MAX }).collect();
+        assert_eq!(interpolation_search_by_key(&skewed, &60_000, counted), Ok(60_000));
+        assert!(probes.take() <= 3 * 2 * 16 + 3);
+
+        // Fibonacci search stays within log_phi(n) + 2 comparisons
+        for target in [0, 3, 5_000_000, 7_340_030] {
+            let expected = binary_search(&uniform, &target);
+            assert_eq!(fibonacci_search_by(&uniform, |x| counted(x).cmp(&target)), expected);
+            assert!(probes.take() <= 32);
+        }
+    }
+
+    #[test]
+    fn test_partition_point_over_integers() {
+        // Agrees with the slice search on an explicit sequence
+        let arr = pseudo_random(200, 50, 3);
+        let mut sorted = arr.clone();
+        sorted.sort();
+        for target in 0..52 {
+            let found = partition_point(0..sorted.len(), |&i| sorted[i] < target);
+            assert_eq!(found, lower_bound(&sorted, &target));
+        }
+
+        // The whole range of a type, signed and unsigned, without overflowing
+        assert_eq!(partition_point(i8::MIN..i8::MAX, |&x| x < 100), 100);
+        assert_eq!(partition_point(i8::MIN..i8::MAX, |_| true), i8::MAX);
+        assert_eq!(partition_point(i8::MIN..i8::MAX, |_| false), i8::MIN);
+        assert_eq!(partition_point(u64::MIN..u64::MAX, |&x| x < u64::MAX - 1), u64::MAX - 1);
+        assert_eq!(partition_point(i128::MIN..i128::MAX, |&x| x < -1), -1);
+        assert_eq!(partition_point(5..5, |_: &u32| true), 5);
+
+        // The integer square root, with a logarithmic number of evaluations
+        let mut evaluations = 0;
+        let root = partition_point(0..u32::MAX as u64, |&x| {
+            evaluations += 1;
+            x * x <= 1_000_000_007
+        }) - 1;
+        assert_eq!(root, 31_622);
+        assert!(evaluations <= 33);
+    }
+
+    #[test]
+    fn test_bisect_and_find_root() {
+        let tolerance = Tolerance::new(1e-12);
+        let cube_root = bisect(0.0, 10.0, &tolerance, |x| x * x * x < 10.0);
+        assert!(cube_root.converged);
+        assert!(cube_root.width() <= 1e-12);
+        assert!((cube_root.midpoint() - 10f64.cbrt()).abs() < 1e-12);
+
+        // The predicate holding everywhere or nowhere ends at the matching end
+        assert!(bisect(-1.0, 1.0, &tolerance, |_| true).low > 1.0 - 1e-12);
+        assert!(bisect(-1.0, 1.0, &tolerance, |_| false).high < -1.0 + 1e-12);
+        assert_eq!(bisect(3.0, 3.0, &tolerance, |_| true).iterations, 0);
+
+        // Running out of steps is reported rather than hidden
+        let limited = Tolerance { width: 0.0, max_iterations: 10 };
+        let rough = bisect(0.0, 1024.0, &limited, |x| x < 700.5);
+        assert!(!rough.converged);
+        assert_eq!(rough.iterations, 10);
+        assert_eq!((rough.low, rough.high), (700.0, 701.0));
+
+        // A tolerance of zero stops once the ends are adjacent floats
+        let exact = bisect(1.0, 2.0, &Tolerance::new(0.0), |x| x * x < 2.0);
+        assert!(exact.converged);
+        assert_eq!(exact.high, f64::from_bits(exact.low.to_bits() + 1));
+
+        // The default step limit is enough to reach adjacent floats anywhere, even near zero
+        let tiny = bisect(0.0, 1.0, &Tolerance::new(0.0), |x| x < 1e-300);
+        assert!(tiny.converged);
+        assert_eq!(tiny.high, 1e-300);
+        for target in [0.0, -1e-310, 5e-324, 1.0, f64::MAX] {
+            let whole = bisect(-f64::MAX, f64::MAX, &Tolerance::new(0.0), |x| x < target);
+            assert!(whole.converged, "{:e}", target);
+            assert_eq!(whole.high, target);
+        }
+        let widest = Bracket { low: -f64::MAX, high: f64::MAX, iterations: 0, converged: false };
+        assert_eq!(widest.midpoint(), 0.0);
+
+        // Decreasing functions work too, since only the sign change matters
+        let root = find_root(0.0, 3.0, &tolerance, |x| 1.0 - x * x / 4.0).unwrap();
+        assert!((root.midpoint() - 2.0).abs() < 1e-12);
+        assert_eq!(find_root(0.0, 1.0, &tolerance, |x| x + 1.0), None);
+
+        // A root exactly at an end is found there, whichever sign the zero has
+        let at_low = find_root(0.0, 1.0, &tolerance, |x| x).unwrap();
+        assert_eq!((at_low.low, at_low.high, at_low.iterations), (0.0, 0.0, 0));
+        assert!(at_low.converged);
+        let at_high = find_root(-2.0, 3.0, &tolerance, |x| -(x - 3.0)).unwrap();
+        assert_eq!((at_high.low, at_high.high), (3.0, 3.0));
+        assert_eq!(find_root(-1.0, 1.0, &tolerance, |_| -0.0).unwrap().width(), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bisect_rejects_an_empty_interval() {
+        bisect(1.0, 0.0, &Tolerance::default(), |_| true);
+    }
+
+    #[test]
+    fn test_unimodal_searches() {
+        let tolerance = Tolerance::new(1e-9);
+        for &minimum in &[-7.25, 0.0, 0.3, 9.99] {
+            let f = |x: f64| (x - minimum).abs() + 2.0;
+            let ternary = ternary_search(-10.0, 10.0, &tolerance, f);
+            let golden = golden_section_search(-10.0, 10.0, &tolerance, f);
+            assert!(ternary.converged && golden.converged);
+            assert!((ternary.midpoint() - minimum).abs() < 1e-9);
+            assert!((golden.midpoint() - minimum).abs() < 1e-9);
+        }
+
+        // Maximizing by negating, with the maximum at an end of the interval
+        let best = golden_section_search(0.0, 1.0, &tolerance, |x| -x);
+        assert!(best.high > 1.0 - 1e-9);
+
+        // Golden-section search narrows the interval with fewer evaluations
+        let mut ternary_evaluations = 0;
+        ternary_search(0.0, 100.0, &tolerance, |x| {
+            ternary_evaluations += 1;
+            (x - 42.0).powi(2)
+        });
+        let mut golden_evaluations = 0;
+        golden_section_search(0.0, 100.0, &tolerance, |x| {
+            golden_evaluations += 1;
+            (x - 42.0).powi(2)
+        });
+        assert!(golden_evaluations < ternary_evaluations);
+
+        // The whole range of f64, whose width does not fit in an f64
+        for minimum in [0.0, 1.0, -1e300, 1e308] {
+            let f = |x: f64| (x / 2.0 - minimum / 2.0).abs();
+            let precision = 1e-6f64.max(minimum.abs() * 1e-15);
+            for found in [
+                ternary_search(-f64::MAX, f64::MAX, &Tolerance::new(1e-6), f),
+                golden_section_search(-f64::MAX, f64::MAX, &Tolerance::new(1e-6), f),
+            ] {
+                assert!(found.converged && found.iterations > 0, "{:e}: {:?}", minimum, found);
+                assert!(found.width().is_finite());
+                assert!((found.midpoint() - minimum).abs() <= precision, "{:e}: {:?}", minimum, found);
+            }
+        }
+
+        let limited = Tolerance { width: 1e-9, max_iterations: 5 };
+        let rough = ternary_search(0.0, 1.0, &limited, |x| x * x);
+        assert!(!rough.converged);
+        assert_eq!(rough.iterations, 5);
+    }
+
     #[test]
     fn test_graph_dfs_and_bfs() {
         // Test Graph with usize
@@ -188,4 +642,1952 @@ mod sort_tests {
         bubble_sort(&mut arr);
         assert_eq!(arr, ['a', 'b', 'c']);
     }
-}
\ No newline at end of file
+
+    type SortBy<T> = fn(&mut [T], fn(&T, &T) -> std::cmp::Ordering);
+    type SortByKey<T, K> = fn(&mut [T], fn(&T) -> K);
+
+    #[test]
+    fn test_sort_by_comparator() {
+        let sorts: [SortBy<i32>; 6] = [
+            merge_sort_by,
+            heap_sort_by,
+            quick_sort_by,
+            insertion_sort_by,
+            selection_sort_by,
+            bubble_sort_by,
+        ];
+        for sort in sorts {
+            let mut arr = [4, 1, 5, 2, 3];
+            sort(&mut arr, |a, b| b.cmp(a));
+            assert_eq!(arr, [5, 4, 3, 2, 1]);
+
+            let mut arr: [i32; 0] = [];
+            sort(&mut arr, |a, b| b.cmp(a));
+            assert_eq!(arr, []);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_key() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Record {
+            name: &'static str,
+            width: u32,
+            height: u32,
+        }
+
+        fn area(r: &Record) -> u32 {
+            r.width * r.height
+        }
+
+        let records = vec![
+            Record { name: "c", width: 3, height: 3 },
+            Record { name: "a", width: 1, height: 1 },
+            Record { name: "b", width: 2, height: 2 },
+        ];
+        let sorts: [SortByKey<Record, u32>; 6] = [
+            merge_sort_by_key,
+            heap_sort_by_key,
+            quick_sort_by_key,
+            insertion_sort_by_key,
+            selection_sort_by_key,
+            bubble_sort_by_key,
+        ];
+        for sort in sorts {
+            let mut arr = records.clone();
+            sort(&mut arr, area);
+            let names: Vec<_> = arr.iter().map(|r| r.name).collect();
+            assert_eq!(names, ["a", "b", "c"]);
+        }
+    }
+
+    /// Sorts `arr` with `quick_sort_by` and returns the number of comparisons it made.
+    fn count_quick_sort_comparisons(arr: &mut [usize]) -> usize {
+        let mut comparisons = 0;
+        quick_sort_by(arr, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        comparisons
+    }
+
+    /// Builds Musser's median-of-3 killer sequence of length `2 * k`.
+    fn median_of_three_killer(k: usize) -> Vec<usize> {
+        let mut arr = vec![0; 2 * k];
+        for i in 1..=k {
+            if i % 2 == 1 {
+                arr[i - 1] = i;
+                arr[i] = k + i;
+            }
+            arr[k + i - 1] = 2 * i;
+        }
+        arr
+    }
+
+    /// Builds a worst-case input for `quick_sort` using McIlroy's "A Killer Adversary for
+    /// Quicksort": values are only fixed once the sort compares them, always in the way that
+    /// hurts the current pivot candidate most.
+    fn mcilroy_adversary(n: usize) -> Vec<usize> {
+        let gas = n;
+        let mut val = vec![gas; n];
+        let mut solid = 0;
+        let mut candidate = 0;
+        let mut ptr: Vec<usize> = (0..n).collect();
+        quick_sort_by(&mut ptr, |&x, &y| {
+            if val[x] == gas && val[y] == gas {
+                if x == candidate {
+                    val[x] = solid;
+                } else {
+                    val[y] = solid;
+                }
+                solid += 1;
+            }
+            if val[x] == gas {
+                candidate = x;
+            } else if val[y] == gas {
+                candidate = y;
+            }
+            val[x].cmp(&val[y])
+        });
+        val
+    }
+
+    #[test]
+    fn test_quick_sort_killer_sequences() {
+        let n = 20_000;
+        let inputs: Vec<(&str, Vec<usize>)> = vec![
+            ("sorted", (0..n).collect()),
+            ("reversed", (0..n).rev().collect()),
+            ("all equal", vec![7; n]),
+            ("organ pipe", (0..n / 2).chain((0..n / 2).rev()).collect()),
+            ("sawtooth", (0..n).map(|i| i % 64).collect()),
+            ("median-of-3 killer", median_of_three_killer(n / 2)),
+            ("mcilroy adversary", mcilroy_adversary(n)),
+        ];
+
+        // Generous bound well below the ~n^2 / 2 comparisons of a degenerate quick sort
+        let log_n = usize::BITS - n.leading_zeros();
+        let bound = 8 * n * log_n as usize;
+
+        for (name, input) in inputs {
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input;
+            let comparisons = count_quick_sort_comparisons(&mut arr);
+            assert_eq!(arr, expected, "{} input was not sorted", name);
+            assert!(comparisons <= bound, "{} input took {} comparisons", name, comparisons);
+        }
+    }
+
+    #[test]
+    fn test_quick_sort_large_sorted_input() {
+        // A naive recursive quick sort overflows the stack on this input
+        let mut arr: Vec<u32> = (0..1_000_000).collect();
+        quick_sort(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_merge_sort_without_clone() {
+        // Deliberately neither `Clone` nor `Copy`
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Handle(Box<u64>);
+
+        let values = pseudo_random(1000, 100, 42);
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut arr: Vec<Handle> = values.iter().map(|&v| Handle(Box::new(v))).collect();
+        merge_sort(&mut arr);
+        assert_eq!(arr.iter().map(|h| *h.0).collect::<Vec<_>>(), expected);
+
+        let mut arr: Vec<Handle> = values.iter().map(|&v| Handle(Box::new(v))).collect();
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr.iter().map(|h| *h.0).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_teaching_sorts() {
+        type Sort = fn(&mut [u64]);
+        let sorts: [(&str, Sort); 9] = [
+            ("shell (Shell gaps)", |arr| shell_sort_with_gaps(arr, GapSequence::Shell)),
+            ("shell (Knuth gaps)", |arr| shell_sort_with_gaps(arr, GapSequence::Knuth)),
+            ("shell (Ciura gaps)", shell_sort),
+            ("shell (Sedgewick gaps)", |arr| shell_sort_with_gaps(arr, GapSequence::Sedgewick)),
+            ("comb", comb_sort),
+            ("cocktail shaker", cocktail_shaker_sort),
+            ("gnome", gnome_sort),
+            ("cycle", cycle_sort),
+            ("odd-even", odd_even_sort),
+        ];
+
+        let mut inputs: Vec<Vec<u64>> = vec![vec![], vec![1], vec![2, 1], (0..300).collect(), (0..300).rev().collect()];
+        for (len, modulus) in [(3, 100), (17, 3), (300, 1), (300, 5), (1000, u64::MAX)] {
+            inputs.push(pseudo_random(len, modulus, (len as u64).wrapping_add(modulus)));
+        }
+        for (name, sort) in sorts {
+            for input in &inputs {
+                let mut expected = input.clone();
+                expected.sort();
+                let mut arr = input.clone();
+                sort(&mut arr);
+                assert_eq!(arr, expected, "{} sort failed on {:?}", name, input);
+            }
+        }
+
+        let mut arr = ['c', 'b', 'a'];
+        cocktail_shaker_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, ['c', 'b', 'a']);
+        odd_even_sort_by_key(&mut arr, |&c| c);
+        assert_eq!(arr, ['a', 'b', 'c']);
+        gnome_sort_by(&mut arr, |a, b| b.cmp(a));
+        comb_sort_by_key(&mut arr, |&c| c);
+        assert_eq!(arr, ['a', 'b', 'c']);
+        shell_sort_with_gaps_by(&mut arr, GapSequence::Knuth, |a, b| b.cmp(a));
+        assert_eq!(arr, ['c', 'b', 'a']);
+    }
+
+    #[test]
+    fn test_gap_sequences() {
+        assert_eq!(GapSequence::Shell.gaps(100), [50, 25, 12, 6, 3, 1]);
+        assert_eq!(GapSequence::Knuth.gaps(100), [40, 13, 4, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(100), [57, 23, 10, 4, 1]);
+        assert_eq!(GapSequence::Sedgewick.gaps(100), [77, 23, 8, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(10_000), [8858, 3937, 1750, 701, 301, 132, 57, 23, 10, 4, 1]);
+        for gaps in [GapSequence::Shell, GapSequence::Knuth, GapSequence::Ciura, GapSequence::Sedgewick] {
+            assert!(gaps.gaps(0).is_empty());
+            assert!(gaps.gaps(1).is_empty());
+            assert_eq!(gaps.gaps(2), [1]);
+        }
+    }
+
+    #[test]
+    fn test_cycle_sort_minimizes_writes() {
+        use crate::algorithms::instrumentation::instrumentation::SortStats;
+        use crate::algorithms::sorting::sorting::{cycle_sort_impl, SortOps};
+
+        fn count_swaps(arr: &mut [u64]) -> usize {
+            let mut stats = SortStats::default();
+            cycle_sort_impl(arr, &mut SortOps::new(&mut u64::cmp, &mut stats));
+            stats.swaps
+        }
+
+        // Every swap finishes one element, and the last element of each cycle comes for free
+        let mut arr = [2, 3, 1, 5, 4, 6];
+        assert_eq!(count_swaps(&mut arr), 3);
+        assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(count_swaps(&mut arr), 0);
+
+        // Elements equal to one already in place are never moved
+        for input in [pseudo_random(200, 5, 61), pseudo_random(200, u64::MAX, 67)] {
+            let mut expected = input.clone();
+            expected.sort();
+            let misplaced = input.iter().zip(&expected).filter(|(a, b)| a != b).count();
+            let mut arr = input;
+            let swaps = count_swaps(&mut arr);
+            assert_eq!(arr, expected);
+            assert!(swaps < misplaced.max(1), "{} swaps for {} misplaced elements", swaps, misplaced);
+        }
+    }
+
+    #[test]
+    fn test_quick_sort_3way() {
+        let mut arr = [3, 2, 1];
+        quick_sort_3way(&mut arr);
+        assert_eq!(arr, [1, 2, 3]);
+
+        let mut arr = ['c', 'b', 'a', 'b'];
+        quick_sort_3way_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, ['c', 'b', 'b', 'a']);
+
+        let mut arr = [-3, 1, -2, 3, 2];
+        quick_sort_3way_by_key(&mut arr, |x: &i32| x.abs());
+        assert_eq!(arr.map(i32::abs), [1, 2, 2, 3, 3]);
+
+        for len in [0, 1, 2, 17, 1000, 10_000] {
+            for distinct in [1, 2, 5, 1000, u64::MAX] {
+                let mut arr = pseudo_random(len, distinct, len as u64 ^ distinct);
+                let mut expected = arr.clone();
+                expected.sort();
+                quick_sort_3way(&mut arr);
+                assert_eq!(arr, expected, "len {} with {} distinct values", len, distinct);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quick_sort_3way_skips_equal_elements() {
+        // An all-equal slice is done after a single partition
+        let n = 100_000;
+        let mut arr = vec![7; n];
+        let mut comparisons = 0;
+        quick_sort_3way_by(&mut arr, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(comparisons <= 2 * n, "all equal input took {} comparisons", comparisons);
+
+        // With a handful of distinct values, each one is set aside once it becomes the pivot
+        let input = pseudo_random(n, 4, 59);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input.clone();
+        let mut three_way_comparisons = 0;
+        quick_sort_3way_by(&mut arr, |a, b| {
+            three_way_comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(arr, expected);
+        assert!(three_way_comparisons <= 6 * n, "few distinct values took {} comparisons", three_way_comparisons);
+
+        let mut arr = input;
+        let mut two_way_comparisons = 0;
+        quick_sort_by(&mut arr, |a, b| {
+            two_way_comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(three_way_comparisons * 2 < two_way_comparisons);
+    }
+
+    #[test]
+    fn test_quick_sort_3way_killer_sequences() {
+        let n: usize = 20_000;
+        let log_n = usize::BITS - n.leading_zeros();
+        let bound = 8 * n * log_n as usize;
+        for input in [median_of_three_killer(n / 2), mcilroy_adversary(n), (0..n).rev().collect()] {
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input;
+            let mut comparisons = 0;
+            quick_sort_3way_by(&mut arr, |a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert_eq!(arr, expected);
+            assert!(comparisons <= bound, "took {} comparisons", comparisons);
+        }
+    }
+
+    #[test]
+    fn test_merge_sort_is_stable() {
+        for len in [0, 1, 2, 3, 10, 33, 500, 5000] {
+            // Few distinct keys, tagged with their original position
+            let keys = pseudo_random(len, 5, len as u64 + 7);
+            let tagged: Vec<(u64, usize)> = keys.into_iter().zip(0..).collect();
+            let mut expected = tagged.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            let mut arr = tagged.clone();
+            merge_sort_by_key(&mut arr, |&(key, _)| key);
+            assert_eq!(arr, expected);
+
+            let mut arr = tagged.clone();
+            bottom_up_merge_sort_by(&mut arr, |a, b| a.0.cmp(&b.0));
+            assert_eq!(arr, expected);
+
+            let mut arr = tagged.clone();
+            in_place_merge_sort_by_key(&mut arr, |&(key, _)| key);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_in_place_merge_sort() {
+        let random = pseudo_random(20_000, u64::MAX, 59);
+        let sorted: Vec<u64> = (0..20_000).collect();
+        let reversed: Vec<u64> = sorted.iter().rev().copied().collect();
+        for input in [random, sorted, reversed, vec![7; 1000]] {
+            let mut expected = input.clone();
+            expected.sort();
+            let mut arr = input;
+            in_place_merge_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+
+        let mut arr = pseudo_random(1000, 100, 61);
+        in_place_merge_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert!(arr.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_merge_sort_with_buffer_reuse() {
+        let mut buf = vec![String::from("stale")];
+        let mut arr: Vec<String> = pseudo_random(100, 1000, 3).iter().map(|v| v.to_string()).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+
+        merge_sort_with_buffer(&mut arr, &mut buf);
+        assert_eq!(arr, expected);
+        assert!(buf.is_empty());
+        let capacity = buf.capacity();
+        assert!(capacity >= 50);
+
+        // A second, shorter sort reuses the buffer without growing it
+        let mut arr: Vec<String> = arr.into_iter().rev().take(60).collect();
+        merge_sort_with_buffer_by(&mut arr, &mut buf, |a, b| b.cmp(a));
+        assert!(arr.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_merge_sort_panic_safety() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let values: Vec<Rc<u64>> = pseudo_random(200, 50, 11).into_iter().map(Rc::new).collect();
+        for panic_after in [1, 10, 100, 500] {
+            let mut arr = values.clone();
+            let mut comparisons = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                merge_sort_by(&mut arr, |a, b| {
+                    comparisons += 1;
+                    if comparisons == panic_after {
+                        panic!("comparator failure");
+                    }
+                    a.cmp(b)
+                });
+            }));
+            assert!(result.is_err());
+
+            // Every element is still present exactly once: nothing leaked or dropped twice
+            let mut remaining: Vec<u64> = arr.iter().map(|v| **v).collect();
+            let mut original: Vec<u64> = values.iter().map(|v| **v).collect();
+            remaining.sort();
+            original.sort();
+            assert_eq!(remaining, original);
+            drop(arr);
+            assert!(values.iter().all(|v| Rc::strong_count(v) == 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::*;
+
+    fn descending_with_duplicates(len: usize) -> Vec<u64> {
+        (0..len as u64).map(|i| (len as u64 - i) / 3).collect()
+    }
+
+    #[test]
+    fn test_par_merge_sort() {
+        let input = descending_with_duplicates(100_000);
+        let mut expected = input.clone();
+        expected.sort();
+
+        for config in [ParallelConfig::new(4, 1000), ParallelConfig::new(1, 1000), ParallelConfig::new(8, 0)] {
+            let mut arr = input.clone();
+            par_merge_sort(&mut arr, &config);
+            assert_eq!(arr, expected);
+        }
+
+        let mut arr: [u64; 0] = [];
+        par_merge_sort(&mut arr, &ParallelConfig::default());
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_par_merge_sort_by_is_stable() {
+        let tagged: Vec<(u64, usize)> = descending_with_duplicates(20_000).into_iter().zip(0..).collect();
+        let mut expected = tagged.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut arr = tagged;
+        par_merge_sort_by(&mut arr, &ParallelConfig::new(4, 500), |a, b| a.0.cmp(&b.0));
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_par_quick_sort() {
+        let input = descending_with_duplicates(100_000);
+        let mut expected = input.clone();
+        expected.sort();
+
+        for config in [ParallelConfig::new(4, 1000), ParallelConfig::new(1, 1000), ParallelConfig::new(8, 0)] {
+            let mut arr = input.clone();
+            par_quick_sort(&mut arr, &config);
+            assert_eq!(arr, expected);
+        }
+
+        let mut arr = input;
+        par_quick_sort_by(&mut arr, &ParallelConfig::new(3, 100), |a, b| b.cmp(a));
+        expected.reverse();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_par_quick_sort_skewed_and_duplicate_heavy() {
+        // A few distinct values, one value making up nearly everything, and a single value
+        let few_values = pseudo_random(50_000, 3, 11);
+        let mut skewed = vec![7u64; 50_000];
+        skewed.extend(pseudo_random(500, 1000, 12));
+        let constant = vec![5u64; 20_000];
+
+        for input in [few_values, skewed, constant] {
+            let mut expected = input.clone();
+            expected.sort();
+            for config in [ParallelConfig::new(4, 100), ParallelConfig::new(7, 0), ParallelConfig::new(2, 1)] {
+                let mut arr = input.clone();
+                par_quick_sort(&mut arr, &config);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod radix_tests {
+    use super::*;
+
+    /// Sorts a copy of `arr` with `merge_sort`, as the expected result.
+    fn merge_sorted<T: Ord + Clone>(arr: &[T]) -> Vec<T> {
+        let mut expected = arr.to_vec();
+        merge_sort(&mut expected);
+        expected
+    }
+
+    #[test]
+    fn test_radix_sort() {
+        let raw = pseudo_random(5000, u64::MAX, 1);
+
+        let mut arr_u32: Vec<u32> = raw.iter().map(|&v| v as u32).collect();
+        let expected = merge_sorted(&arr_u32);
+        radix_sort(&mut arr_u32);
+        assert_eq!(arr_u32, expected);
+
+        let mut arr_u64 = raw.clone();
+        let expected = merge_sorted(&arr_u64);
+        radix_sort(&mut arr_u64);
+        assert_eq!(arr_u64, expected);
+
+        let mut arr_i64: Vec<i64> = raw.iter().map(|&v| v as i64).collect();
+        arr_i64.extend([i64::MIN, i64::MAX, 0, -1, 1]);
+        let expected = merge_sorted(&arr_i64);
+        radix_sort(&mut arr_i64);
+        assert_eq!(arr_i64, expected);
+
+        // Small values only exercise the low bytes
+        let mut arr_i8: Vec<i8> = raw.iter().map(|&v| (v % 7) as i8 - 3).collect();
+        let expected = merge_sorted(&arr_i8);
+        radix_sort(&mut arr_i8);
+        assert_eq!(arr_i8, expected);
+
+        let mut arr: [u16; 0] = [];
+        radix_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_msd_radix_sort() {
+        let raw = pseudo_random(5000, u64::MAX, 2);
+
+        let mut arr_u64 = raw.clone();
+        let expected = merge_sorted(&arr_u64);
+        msd_radix_sort(&mut arr_u64);
+        assert_eq!(arr_u64, expected);
+
+        let mut arr_i32: Vec<i32> = raw.iter().map(|&v| (v % 1000) as i32 - 500).collect();
+        let expected = merge_sorted(&arr_i32);
+        msd_radix_sort(&mut arr_i32);
+        assert_eq!(arr_i32, expected);
+    }
+
+    #[test]
+    fn test_radix_sort_by_key() {
+        // Not `Clone`, and tagged with the original position to check stability
+        #[derive(Debug, PartialEq)]
+        struct Row {
+            id: i64,
+            position: usize,
+        }
+
+        let ids: Vec<i64> = pseudo_random(3000, u64::MAX, 3).iter().map(|&v| (v % 100) as i64 - 50).collect();
+        let mut rows: Vec<Row> = ids.iter().enumerate().map(|(position, &id)| Row { id, position }).collect();
+        radix_sort_by_key(&mut rows, |row| row.id);
+
+        let mut expected: Vec<(i64, usize)> = ids.iter().copied().zip(0..).collect();
+        merge_sort_by_key(&mut expected, |&(id, _)| id);
+        let actual: Vec<(i64, usize)> = rows.iter().map(|row| (row.id, row.position)).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_counting_sort() {
+        let mut arr: Vec<i64> = pseudo_random(5000, u64::MAX, 4).iter().map(|&v| (v % 200) as i64 - 100).collect();
+        let expected = merge_sorted(&arr);
+        counting_sort(&mut arr);
+        assert_eq!(arr, expected);
+
+        let mut arr = [u64::MAX, u64::MAX - 3, u64::MAX - 1];
+        counting_sort(&mut arr);
+        assert_eq!(arr, [u64::MAX - 3, u64::MAX - 1, u64::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "key range is too large for counting sort")]
+    fn test_counting_sort_range_too_large() {
+        // A count for every value would take 2^63 bytes, which cannot be allocated anywhere
+        counting_sort(&mut [0u64, 1 << 60]);
+    }
+}
+
+#[cfg(test)]
+mod instrumentation_tests {
+    use super::*;
+    use crate::algorithms::instrumentation::instrumentation::{
+        bubble_sort_instrumented_by, heap_sort_instrumented_by, insertion_sort_instrumented_by,
+        merge_sort_instrumented_by, quick_sort_instrumented_by, selection_sort_instrumented_by,
+    };
+
+    type InstrumentedBy = fn(&mut [u64], &mut dyn FnMut(&u64, &u64) -> std::cmp::Ordering) -> SortStats;
+
+    #[test]
+    fn test_instrumented_comparisons_match_comparator_calls() {
+        let sorts: [(&str, InstrumentedBy); 6] = [
+            ("merge", |arr, compare| merge_sort_instrumented_by(arr, compare)),
+            ("heap", |arr, compare| heap_sort_instrumented_by(arr, compare)),
+            ("quick", |arr, compare| quick_sort_instrumented_by(arr, compare)),
+            ("insertion", |arr, compare| insertion_sort_instrumented_by(arr, compare)),
+            ("selection", |arr, compare| selection_sort_instrumented_by(arr, compare)),
+            ("bubble", |arr, compare| bubble_sort_instrumented_by(arr, compare)),
+        ];
+        let input = pseudo_random(300, 100, 5);
+        let mut expected = input.clone();
+        expected.sort();
+
+        for (name, sort) in sorts {
+            let mut arr = input.clone();
+            let mut calls = 0;
+            let stats = sort(&mut arr, &mut |a, b| {
+                calls += 1;
+                a.cmp(b)
+            });
+            assert_eq!(arr, expected, "{} sort", name);
+            assert_eq!(stats.comparisons, calls, "{} sort", name);
+        }
+    }
+
+    #[test]
+    fn test_instrumented_counts() {
+        let n = 64;
+        let reversed: Vec<u64> = (0..n).rev().collect();
+
+        // Every pair is compared and swapped exactly once
+        let stats = bubble_sort_instrumented(&mut reversed.clone());
+        let pairs = (n * (n - 1) / 2) as usize;
+        assert_eq!(stats, SortStats { comparisons: pairs, swaps: pairs, writes: 0, max_depth: 0 });
+
+        let stats = insertion_sort_instrumented(&mut reversed.clone());
+        assert_eq!((stats.comparisons, stats.swaps), (pairs, pairs));
+
+        // Selection sort swaps each mirrored pair once
+        let stats = selection_sort_instrumented(&mut reversed.clone());
+        assert_eq!((stats.comparisons, stats.swaps), (pairs, n as usize / 2));
+
+        // Merge sort only moves elements, and recurses log2(n) levels deep
+        let stats = merge_sort_instrumented(&mut reversed.clone());
+        assert_eq!(stats.swaps, 0);
+        assert!(stats.writes > 0);
+        assert_eq!(stats.max_depth, 6);
+
+        let stats = heap_sort_instrumented(&mut reversed.clone());
+        assert_eq!(stats.writes, 0);
+        assert!(stats.max_depth >= 1 && stats.max_depth <= 6);
+
+        let stats = quick_sort_instrumented(&mut reversed.clone());
+        assert_eq!(stats.writes, 0);
+        assert!(stats.max_depth <= 6);
+    }
+
+    #[test]
+    fn test_instrumented_sorts_compare_algorithms() {
+        let input = pseudo_random(1000, 1_000_000, 9);
+
+        let bubble = bubble_sort_instrumented(&mut input.clone());
+        let heap = heap_sort_instrumented(&mut input.clone());
+        let quick = quick_sort_instrumented(&mut input.clone());
+
+        assert!(bubble.comparisons > 10 * heap.comparisons);
+        assert!(bubble.comparisons > 10 * quick.comparisons);
+        assert!(bubble.swaps > heap.swaps);
+    }
+}
+
+#[cfg(test)]
+mod trace_tests {
+    use super::*;
+
+    type Traced = fn(&mut [u64]) -> Vec<SortEvent<u64>>;
+    type Instrumented = fn(&mut [u64]) -> SortStats;
+
+    #[test]
+    fn test_traced_sorts_replay_to_sorted_state() {
+        let sorts: [(&str, Traced, Instrumented); 6] = [
+            ("merge", merge_sort_traced, merge_sort_instrumented),
+            ("heap", heap_sort_traced, heap_sort_instrumented),
+            ("quick", quick_sort_traced, quick_sort_instrumented),
+            ("insertion", insertion_sort_traced, insertion_sort_instrumented),
+            ("selection", selection_sort_traced, selection_sort_instrumented),
+            ("bubble", bubble_sort_traced, bubble_sort_instrumented),
+        ];
+        let initial = pseudo_random(200, 50, 13);
+
+        for (name, traced, instrumented) in sorts {
+            let mut arr = initial.clone();
+            let events = traced(&mut arr);
+            let states = replay(&initial, &events);
+            assert_eq!(states.last().unwrap(), &arr, "{} sort", name);
+            assert!(arr.windows(2).all(|w| w[0] <= w[1]), "{} sort", name);
+
+            // The trace has one event for every operation the instrumentation counts
+            let stats = instrumented(&mut initial.clone());
+            let count = |f: fn(&SortEvent<u64>) -> bool| events.iter().filter(|e| f(e)).count();
+            assert_eq!(count(|e| matches!(e, SortEvent::Compare { .. })), stats.comparisons, "{} sort", name);
+            assert_eq!(count(|e| matches!(e, SortEvent::Swap { .. })), stats.swaps, "{} sort", name);
+            assert_eq!(count(|e| matches!(e, SortEvent::Write { .. })), stats.writes, "{} sort", name);
+        }
+    }
+
+    #[test]
+    fn test_quick_sort_trace_partitions() {
+        let initial = pseudo_random(500, 1000, 17);
+        let events = quick_sort_traced(&mut initial.clone());
+        let states = replay(&initial, &events);
+
+        let mut partitions = 0;
+        for (event, state) in events.iter().zip(&states) {
+            if let SortEvent::Partition { start, end, pivot } = *event {
+                partitions += 1;
+                assert!(start <= pivot && pivot < end);
+                assert!(state[start..pivot].iter().all(|x| *x <= state[pivot]));
+                assert!(state[pivot + 1..end].iter().all(|x| *x > state[pivot]));
+            }
+        }
+        assert!(partitions > 0);
+    }
+
+    #[test]
+    fn test_heap_sort_trace_boundaries() {
+        let initial = pseudo_random(100, 1000, 19);
+        let events = heap_sort_traced(&mut initial.clone());
+        let states = replay(&initial, &events);
+
+        let mut boundaries = Vec::new();
+        for (event, state) in events.iter().zip(&states) {
+            if let SortEvent::HeapBoundary { start, end } = *event {
+                boundaries.push(end);
+                // Everything behind the heap is already in its final place
+                let max = state[start..end].iter().max().unwrap();
+                assert!(state[end..].iter().all(|x| x >= max));
+                assert!(state[end..].windows(2).all(|w| w[0] <= w[1]));
+            }
+        }
+        assert_eq!(boundaries, (1..=100).rev().collect::<Vec<_>>());
+    }
+}
+
+#[cfg(test)]
+mod timsort_tests {
+    use super::*;
+
+    #[test]
+    fn test_tim_sort() {
+        let mut arr = [3, 2, 1];
+        tim_sort(&mut arr);
+        assert_eq!(arr, [1, 2, 3]);
+
+        let mut arr = ['c', 'b', 'a'];
+        tim_sort(&mut arr);
+        assert_eq!(arr, ['a', 'b', 'c']);
+
+        for len in [0, 1, 2, 63, 64, 65, 1000, 10_000] {
+            for modulus in [2, 100, u64::MAX] {
+                let mut arr = pseudo_random(len, modulus, len as u64);
+                let mut expected = arr.clone();
+                expected.sort();
+                tim_sort(&mut arr);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tim_sort_is_stable() {
+        // Tagged records with few distinct keys, arranged as an appended log: a long sorted
+        // prefix, a descending block and a random tail
+        let mut keys: Vec<u64> = (0..3000).map(|i| i / 100).collect();
+        keys.extend((0..500).rev().map(|i| i / 50));
+        keys.extend(pseudo_random(2000, 30, 7));
+        let tagged: Vec<(u64, usize)> = keys.into_iter().zip(0..).collect();
+
+        let mut expected = tagged.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut arr = tagged;
+        tim_sort_by_key(&mut arr, |&(key, _)| key);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_tim_sort_exploits_runs() {
+        let n = 10_000;
+        let count = |arr: &mut [u64]| {
+            let mut comparisons = 0;
+            tim_sort_by(arr, |a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            comparisons
+        };
+
+        // A single ascending or strictly descending run is detected in n - 1 comparisons
+        let mut sorted: Vec<u64> = (0..n).collect();
+        assert_eq!(count(&mut sorted), n as usize - 1);
+        let mut reversed: Vec<u64> = (0..n).rev().collect();
+        assert_eq!(count(&mut reversed), n as usize - 1);
+        assert_eq!(reversed, sorted);
+
+        // Two sorted runs in the wrong order are found in n - 2 comparisons and merged by
+        // galloping in a few more, instead of about n
+        let mut rotated: Vec<u64> = (n / 2..n).chain(0..n / 2).collect();
+        let comparisons = count(&mut rotated);
+        assert_eq!(rotated, sorted);
+        assert!(comparisons < n as usize + 100);
+    }
+
+    #[test]
+    fn test_tim_sort_panic_safety() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let values: Vec<Rc<u64>> = pseudo_random(2000, 50, 23).into_iter().map(Rc::new).collect();
+        for panic_after in [1, 100, 5000, 15_000] {
+            let mut arr = values.clone();
+            let mut comparisons = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                tim_sort_by(&mut arr, |a, b| {
+                    comparisons += 1;
+                    if comparisons == panic_after {
+                        panic!("comparator failure");
+                    }
+                    a.cmp(b)
+                });
+            }));
+            assert!(result.is_err());
+
+            let mut remaining: Vec<u64> = arr.iter().map(|v| **v).collect();
+            let mut original: Vec<u64> = values.iter().map(|v| **v).collect();
+            remaining.sort();
+            original.sort();
+            assert_eq!(remaining, original);
+            drop(arr);
+            assert!(values.iter().all(|v| Rc::strong_count(v) == 1));
+        }
+    }
+}
+
+/// Module containing unit tests for the selection algorithms.
+#[cfg(test)]
+mod selection_tests {
+    use super::*;
+
+    /// Checks that `arr` is partitioned around index `k` and holds the same elements as
+    /// `sorted`.
+    fn assert_selected(arr: &[u64], k: usize, sorted: &[u64]) {
+        assert_eq!(arr[k], sorted[k]);
+        assert!(arr[..k].iter().all(|x| *x <= arr[k]));
+        assert!(arr[k + 1..].iter().all(|x| *x >= arr[k]));
+        let mut rest = arr.to_vec();
+        rest.sort();
+        assert_eq!(rest, sorted);
+    }
+
+    #[test]
+    fn test_select_nth() {
+        let mut arr = [3, 1, 2];
+        assert_eq!(*select_nth(&mut arr, 0), 1);
+        assert_eq!(*select_nth(&mut arr, 2), 3);
+
+        for len in [1, 2, 5, 16, 17, 100, 1000] {
+            for modulus in [2, 10, u64::MAX] {
+                let input = pseudo_random(len, modulus, (len as u64).wrapping_add(modulus));
+                let mut sorted = input.clone();
+                sorted.sort();
+                for k in (0..len).step_by(len / 10 + 1).chain([len - 1]) {
+                    let mut arr = input.clone();
+                    assert_eq!(*select_nth(&mut arr, k), sorted[k]);
+                    assert_selected(&arr, k, &sorted);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_select_nth_by_and_by_key() {
+        let mut arr = [1, 5, 2, 4, 3];
+        assert_eq!(*select_nth_by(&mut arr, 1, |a, b| b.cmp(a)), 4);
+        assert!(arr[..1].iter().all(|&x| x >= 4));
+
+        let mut words = ["ccc", "a", "bb", "dddd"];
+        assert_eq!(*select_nth_by_key(&mut words, 2, |w| w.len()), "ccc");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_nth_out_of_range() {
+        select_nth(&mut [1, 2, 3], 3);
+    }
+
+    /// Builds an input that makes every pivot `select_nth_by` picks as bad as possible, the
+    /// same way as McIlroy's adversary for quick sort: values are only fixed once compared.
+    fn select_adversary(n: usize, k: usize) -> Vec<usize> {
+        let gas = n;
+        let mut val = vec![gas; n];
+        let mut solid = 0;
+        let mut candidate = 0;
+        let mut ptr: Vec<usize> = (0..n).collect();
+        select_nth_by(&mut ptr, k, |&x, &y| {
+            if val[x] == gas && val[y] == gas {
+                if x == candidate {
+                    val[x] = solid;
+                } else {
+                    val[y] = solid;
+                }
+                solid += 1;
+            }
+            if val[x] == gas {
+                candidate = x;
+            } else if val[y] == gas {
+                candidate = y;
+            }
+            val[x].cmp(&val[y])
+        });
+        val
+    }
+
+    #[test]
+    fn test_select_nth_is_linear() {
+        let n = 100_000;
+        let inputs: Vec<(&str, Vec<usize>)> = vec![
+            ("sorted", (0..n).collect()),
+            ("reversed", (0..n).rev().collect()),
+            ("all equal", vec![7; n]),
+            ("two values", (0..n).map(|i| i % 2).collect()),
+            ("organ pipe", (0..n / 2).chain((0..n / 2).rev()).collect()),
+            ("adversary", select_adversary(n, n / 2)),
+        ];
+
+        // A degenerate quickselect would take ~n^2 / 2 comparisons on the adversary
+        for (name, input) in inputs {
+            let mut sorted = input.clone();
+            sorted.sort();
+            for k in [0, n / 3, n / 2, n - 1] {
+                let mut arr = input.clone();
+                let mut comparisons = 0;
+                let selected = *select_nth_by(&mut arr, k, |a, b| {
+                    comparisons += 1;
+                    a.cmp(b)
+                });
+                assert_eq!(selected, sorted[k], "{} input selected the wrong element", name);
+                assert!(comparisons <= 20 * n, "{} input took {} comparisons", name, comparisons);
+            }
+        }
+    }
+
+    #[test]
+    fn test_median_and_percentile() {
+        assert_eq!(median(&mut [5]), Some(&5));
+        assert_eq!(median(&mut [9, 1, 5, 3, 7]), Some(&5));
+        assert_eq!(median(&mut [4, 1, 3, 2]), Some(&2));
+        assert_eq!(median::<u64>(&mut []), None);
+
+        let mut arr: Vec<u64> = (1..=100).rev().collect();
+        assert_eq!(percentile(&mut arr, 0.0), Some(&1));
+        assert_eq!(percentile(&mut arr, 1.0), Some(&1));
+        assert_eq!(percentile(&mut arr, 25.0), Some(&25));
+        assert_eq!(percentile(&mut arr, 50.0), Some(&50));
+        assert_eq!(percentile(&mut arr, 99.5), Some(&100));
+        assert_eq!(percentile(&mut arr, 100.0), Some(&100));
+        assert_eq!(percentile::<u64>(&mut [], 50.0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_percentile_out_of_range() {
+        percentile(&mut [1, 2, 3], 101.0);
+    }
+
+    #[test]
+    fn test_partial_sort() {
+        for len in [0, 1, 2, 10, 100, 1000] {
+            let input = pseudo_random(len, 50, len as u64 + 3);
+            let mut sorted = input.clone();
+            sorted.sort();
+            for k in [0, 1, 2, len / 2, len, len + 1] {
+                let mut arr = input.clone();
+                partial_sort(&mut arr, k);
+                let k = k.min(len);
+                assert_eq!(arr[..k], sorted[..k]);
+                arr.sort();
+                assert_eq!(arr, sorted);
+            }
+        }
+
+        let mut arr = [1, 5, 2, 4, 3];
+        partial_sort_by(&mut arr, 2, |a, b| b.cmp(a));
+        assert_eq!(arr[..2], [5, 4]);
+
+        let mut words = ["ccc", "a", "dddd", "bb"];
+        partial_sort_by_key(&mut words, 3, |w| w.len());
+        assert_eq!(words[..3], ["a", "bb", "ccc"]);
+    }
+
+    #[test]
+    fn test_partial_sort_beats_full_sort() {
+        let n = 100_000;
+        let input = pseudo_random(n, u64::MAX, 11);
+
+        let mut partial = 0;
+        partial_sort_by(&mut input.clone(), 100, |a, b| {
+            partial += 1;
+            a.cmp(b)
+        });
+        let mut full = 0;
+        heap_sort_by(&mut input.clone(), |a, b| {
+            full += 1;
+            a.cmp(b)
+        });
+        assert!(partial * 5 < full, "partial sort took {} comparisons, heap sort {}", partial, full);
+    }
+
+    #[test]
+    fn test_top_k() {
+        for len in [0, 1, 5, 100, 1000] {
+            let input = pseudo_random(len, 50, len as u64 + 5);
+            let mut expected = input.clone();
+            expected.sort_by(|a, b| b.cmp(a));
+            for k in [0, 1, 3, len, len + 1] {
+                let top = top_k(input.iter().copied(), k);
+                assert_eq!(top, expected[..k.min(len)]);
+            }
+        }
+
+        assert_eq!(top_k_by(vec!["bb", "a", "ccc"], 2, |a, b| b.cmp(a)), ["a", "bb"]);
+        let rows = vec![("a", 3), ("b", 9), ("c", 1), ("d", 7)];
+        assert_eq!(top_k_by_key(rows, 2, |&(_, hits)| hits), [("b", 9), ("d", 7)]);
+    }
+
+    #[test]
+    fn test_top_k_streams() {
+        // Only k + 1 items are ever held, so the items need not be collected first
+        let n = 1_000_000u64;
+        let stream = (0..n).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) % n);
+        let top = top_k(stream, 100);
+        assert_eq!(top.len(), 100);
+        assert!(top.windows(2).all(|w| w[0] >= w[1]));
+
+        let mut expected: Vec<u64> = (0..n).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) % n).collect();
+        expected.sort_by(|a, b| b.cmp(a));
+        assert_eq!(top, expected[..100]);
+    }
+}
+
+/// Module containing unit tests for the external sort.
+#[cfg(test)]
+mod external_sort_tests {
+    use super::*;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// Creates an empty directory for one test's files, including its temporary runs.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("external-sort-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config(dir: &Path, memory_budget: usize, merge_fan_in: usize) -> ExternalSortConfig {
+        ExternalSortConfig {
+            memory_budget,
+            merge_fan_in,
+            temp_dir: dir.to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn test_external_sort_lines() {
+        let dir = test_dir("lines");
+        let input = dir.join("input.txt");
+        let output = dir.join("output.txt");
+
+        let mut lines: Vec<String> = pseudo_random(5000, 100_000, 17).iter().map(|n| format!("key-{}", n)).collect();
+        fs::write(&input, lines.join("\n")).unwrap();
+        lines.sort();
+
+        // A budget of a few dozen lines spills over a hundred runs, merged in several passes
+        for (budget, fan_in) in [(usize::MAX, 64), (1000, 64), (1000, 4), (0, 1000)] {
+            let report = external_sort(&input, &output, &LineCodec, &config(&dir, budget, fan_in)).unwrap();
+            assert_eq!(fs::read_to_string(&output).unwrap(), lines.join("\n") + "\n");
+            assert_eq!(report.records, 5000);
+            if budget == usize::MAX {
+                assert_eq!(report, ExternalSortReport { records: 5000, runs: 0, merge_passes: 0 });
+            } else {
+                assert!(report.runs > 100);
+            }
+            if fan_in == 4 {
+                assert!(report.merge_passes >= 4);
+            }
+        }
+
+        // Only the input and output are left behind
+        fs::remove_file(&input).unwrap();
+        fs::remove_file(&output).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_sort_fixed_width_is_stable() {
+        let dir = test_dir("fixed");
+        let input = dir.join("input.bin");
+        let output = dir.join("output.bin");
+
+        // Eight-byte records: a big-endian key with few distinct values, then a sequence number
+        let records: Vec<[u8; 8]> = pseudo_random(3000, 10, 29)
+            .iter()
+            .zip(0u32..)
+            .map(|(&key, seq)| {
+                let mut record = [0; 8];
+                record[..4].copy_from_slice(&(key as u32).to_be_bytes());
+                record[4..].copy_from_slice(&seq.to_be_bytes());
+                record
+            })
+            .collect();
+        fs::write(&input, records.concat()).unwrap();
+
+        let codec = FixedWidthCodec::new(8);
+        let report = external_sort_by(&input, &output, &codec, &config(&dir, 2000, 3), |a, b| a[..4].cmp(&b[..4])).unwrap();
+        assert!(report.runs > 3);
+
+        let mut expected = records;
+        expected.sort_by(|a, b| a[..4].cmp(&b[..4]));
+        assert_eq!(fs::read(&output).unwrap(), expected.concat());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_sort_errors() {
+        let dir = test_dir("errors");
+        let input = dir.join("input.bin");
+        let output = dir.join("output.bin");
+
+        let missing = external_sort(&dir.join("missing"), &output, &LineCodec, &config(&dir, 100, 2));
+        assert_eq!(missing.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+
+        // A trailing partial record is rejected, and the runs spilled before it are removed
+        fs::write(&input, [7; 101]).unwrap();
+        let partial = external_sort(&input, &output, &FixedWidthCodec::new(4), &config(&dir, 40, 2));
+        assert_eq!(partial.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::write(&input, "").unwrap();
+        let empty = external_sort(&input, &output, &LineCodec, &config(&dir, 100, 2)).unwrap();
+        assert_eq!(empty.records, 0);
+        assert_eq!(fs::read(&output).unwrap(), b"");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Module containing unit tests for merging and set operations.
+#[cfg(test)]
+mod merging_tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn sorted_random(len: usize, modulus: u64, seed: u64) -> Vec<u64> {
+        let mut arr = pseudo_random(len, modulus, seed);
+        arr.sort();
+        arr
+    }
+
+    #[test]
+    fn test_merge() {
+        for (len_a, len_b) in [(0, 0), (0, 5), (5, 0), (1, 1000), (100, 100), (1000, 7)] {
+            let a = sorted_random(len_a, 50, 3);
+            let b = sorted_random(len_b, 50, 4);
+            let mut expected = [a.clone(), b.clone()].concat();
+            expected.sort();
+            assert_eq!(merge(&a, &b), expected);
+        }
+
+        // Equal keys keep their order, with `a` first
+        let a = [(1, 'a'), (2, 'a'), (2, 'b')];
+        let b = [(0, 'c'), (2, 'c'), (3, 'c')];
+        let merged = merge_by_key(&a, &b, |&(key, _)| key);
+        assert_eq!(merged, [(0, 'c'), (1, 'a'), (2, 'a'), (2, 'b'), (2, 'c'), (3, 'c')]);
+    }
+
+    #[test]
+    fn test_kmerge() {
+        let shards: Vec<Vec<u64>> = (0..10).map(|i| sorted_random(i * 37, 100, i as u64)).collect();
+        let mut expected = shards.concat();
+        expected.sort();
+
+        let merged = kmerge(shards.clone());
+        assert_eq!(merged.size_hint(), (expected.len(), Some(expected.len())));
+        assert_eq!(merged.collect::<Vec<_>>(), expected);
+
+        // Equal items come out in the order of their iterators
+        let tagged = shards.iter().enumerate().map(|(shard, values)| values.iter().map(move |&v| (v, shard)));
+        let merged: Vec<(u64, usize)> = kmerge_by(tagged, |a, b| a.0.cmp(&b.0)).collect();
+        let mut expected: Vec<(u64, usize)> = merged.clone();
+        expected.sort();
+        assert_eq!(merged, expected);
+
+        assert_eq!(kmerge(Vec::<Vec<u64>>::new()).next(), None);
+    }
+
+    #[test]
+    fn test_kmerge_is_lazy() {
+        // Infinite iterators can be merged as long as only a prefix is taken
+        let multiples = (1..4u64).map(|k| (1..).map(move |i| i * k));
+        let merged: Vec<u64> = kmerge(multiples).take(8).collect();
+        assert_eq!(merged, [1, 2, 2, 3, 3, 4, 4, 5]);
+    }
+
+    /// Computes a set operation on multisets by counting, as a reference.
+    fn reference(a: &[u64], b: &[u64], count: fn(usize, usize) -> usize) -> Vec<u64> {
+        let mut counts: BTreeMap<u64, (usize, usize)> = BTreeMap::new();
+        a.iter().for_each(|&x| counts.entry(x).or_default().0 += 1);
+        b.iter().for_each(|&x| counts.entry(x).or_default().1 += 1);
+        counts.into_iter().flat_map(|(x, (m, n))| std::iter::repeat_n(x, count(m, n))).collect()
+    }
+
+    #[test]
+    fn test_set_operations() {
+        for (len_a, len_b, modulus) in [(0, 0, 10), (0, 20, 10), (50, 50, 20), (10, 1000, 5000), (300, 3, 100)] {
+            let a = sorted_random(len_a, modulus, len_a as u64 + 1);
+            let b = sorted_random(len_b, modulus, len_b as u64 + 2);
+            assert_eq!(union(&a, &b), reference(&a, &b, |m, n| m.max(n)));
+            assert_eq!(intersection(&a, &b), reference(&a, &b, |m, n| m.min(n)));
+            assert_eq!(difference(&a, &b), reference(&a, &b, |m, n| m.saturating_sub(n)));
+            assert_eq!(symmetric_difference(&a, &b), reference(&a, &b, |m, n| m.abs_diff(n)));
+        }
+
+        let by_len = |a: &&str, b: &&str| a.len().cmp(&b.len());
+        assert_eq!(union_by(&["a", "bb"], &["cc", "ddd"], by_len), ["a", "bb", "ddd"]);
+        assert_eq!(intersection_by(&["a", "bb"], &["cc", "ddd"], by_len), ["bb"]);
+        assert_eq!(difference_by(&["a", "bb"], &["cc", "ddd"], by_len), ["a"]);
+        assert_eq!(symmetric_difference_by(&["a", "bb"], &["cc", "ddd"], by_len), ["a", "ddd"]);
+    }
+
+    #[test]
+    fn test_set_operations_gallop_on_skewed_sizes() {
+        let small: Vec<u64> = (0..10).map(|i| i * 100_000 + 7).collect();
+        let large: Vec<u64> = (0..1_000_000).collect();
+
+        let mut comparisons = 0;
+        let mut compare = |a: &u64, b: &u64| {
+            comparisons += 1;
+            a.cmp(b)
+        };
+        let common = intersection_by(&small, &large, &mut compare);
+        assert_eq!(common, small);
+        // Each element of `small` is found in about 2 * log2(100_000) comparisons
+        assert!(comparisons < 10 * 40, "intersection took {} comparisons", comparisons);
+
+        let mut comparisons = 0;
+        let merged = merge_by(&small, &large, |a: &u64, b: &u64| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert_eq!(merged.len(), 1_000_010);
+        assert!(comparisons < 10 * 40, "merge took {} comparisons", comparisons);
+        assert_eq!(union_by(&large, &small, Ord::cmp), large);
+    }
+}
+
+/// Module containing unit tests for the sortedness metrics.
+#[cfg(test)]
+mod sortedness_tests {
+    use super::*;
+
+    fn brute_force_inversions(arr: &[u64]) -> u64 {
+        let mut inversions = 0;
+        for i in 0..arr.len() {
+            for j in i + 1..arr.len() {
+                if arr[i] > arr[j] {
+                    inversions += 1;
+                }
+            }
+        }
+        inversions
+    }
+
+    #[test]
+    fn test_count_inversions() {
+        for len in [0, 1, 2, 3, 17, 100, 500] {
+            for modulus in [2, 10, u64::MAX] {
+                let arr = pseudo_random(len, modulus, len as u64 + 9);
+                assert_eq!(count_inversions(&arr), brute_force_inversions(&arr));
+            }
+        }
+
+        let n = 100_000u64;
+        let reversed: Vec<u64> = (0..n).rev().collect();
+        assert_eq!(count_inversions(&reversed), n * (n - 1) / 2);
+        assert_eq!(count_inversions(&vec![1; 1000]), 0);
+        assert_eq!(count_inversions_by(&reversed, |a, b| b.cmp(a)), 0);
+    }
+
+    #[test]
+    fn test_is_sorted_and_runs() {
+        assert!(is_sorted::<u64>(&[]));
+        assert!(is_sorted(&[1]));
+        assert!(is_sorted(&[1, 1, 2]));
+        assert!(!is_sorted(&[2, 1]));
+        assert!(is_sorted_by(&[3, 2, 2, 1], |a, b| b.cmp(a)));
+        assert!(is_sorted_by_key(&["a", "bb", "cc", "d"][..3], |s| s.len()));
+
+        assert_eq!(longest_sorted_run(&[1]), 1);
+        assert_eq!(longest_sorted_run(&[5, 4, 3]), 1);
+        assert_eq!(longest_sorted_run(&[1, 2, 0, 1, 2, 3, 0]), 4);
+        assert_eq!(longest_sorted_run_by(&[1, 2, 0, 1, 2, 3, 0], |a, b| b.cmp(a)), 2);
+    }
+
+    #[test]
+    fn test_sortedness_report() {
+        assert_eq!(sortedness_report::<u64>(&[]), SortednessReport::default());
+
+        let sorted: Vec<u64> = (0..1000).collect();
+        let report = sortedness_report(&sorted);
+        assert_eq!(report, SortednessReport { len: 1000, inversions: 0, runs: 1, longest_run: 1000, max_displacement: 0 });
+        assert!(report.is_sorted() && report.is_nearly_sorted());
+
+        // A few adjacent swaps leave the input nearly sorted
+        let mut nearly = sorted.clone();
+        for i in (0..1000).step_by(100) {
+            nearly.swap(i, i + 1);
+        }
+        let report = sortedness_report(&nearly);
+        assert_eq!((report.inversions, report.runs, report.max_displacement), (10, 11, 1));
+        assert!(!report.is_sorted() && report.is_nearly_sorted());
+
+        // Moving one element to the far end displaces it, but adds few inversions
+        let mut moved = sorted.clone();
+        moved.rotate_left(1);
+        let report = sortedness_report(&moved);
+        assert_eq!((report.inversions, report.runs, report.max_displacement), (999, 2, 999));
+
+        let shuffled = pseudo_random(1000, u64::MAX, 31);
+        let report = sortedness_report(&shuffled);
+        assert_eq!(report.inversions, brute_force_inversions(&shuffled));
+        assert!(!report.is_nearly_sorted());
+
+        // Equal elements are not displaced relative to each other
+        let report = sortedness_report_by(&[(1, 'a'), (0, 'b'), (1, 'c')], |a, b| a.0.cmp(&b.0));
+        assert_eq!((report.inversions, report.max_displacement), (1, 1));
+    }
+}
+
+/// Module containing unit tests for the adaptive sort dispatcher.
+#[cfg(test)]
+mod adaptive_tests {
+    use super::*;
+
+    #[test]
+    fn test_smart_sort_strategies() {
+        let any = SortOptions::default();
+        let stable = SortOptions { stable: true, constant_memory: false };
+        let constant = SortOptions { stable: false, constant_memory: true };
+        let both = SortOptions { stable: true, constant_memory: true };
+
+        let random = pseudo_random(10_000, u64::MAX, 41);
+        let mut nearly_sorted: Vec<u64> = (0..10_000).collect();
+        nearly_sorted.swap(5000, 5001);
+        let few_values = pseudo_random(10_000, 4, 43);
+        let cases = [
+            (pseudo_random(20, 100, 37), any, SortStrategy::InsertionSort),
+            (nearly_sorted[..500].to_vec(), constant, SortStrategy::InsertionSort),
+            (nearly_sorted.clone(), any, SortStrategy::TimSort),
+            (nearly_sorted.clone(), constant, SortStrategy::HeapSort),
+            (nearly_sorted, both, SortStrategy::InPlaceMergeSort),
+            (random.clone(), any, SortStrategy::QuickSort),
+            (random.clone(), stable, SortStrategy::MergeSort),
+            (random.clone(), constant, SortStrategy::HeapSort),
+            (random, both, SortStrategy::InPlaceMergeSort),
+            (few_values, any, SortStrategy::MergeSort),
+        ];
+
+        for (input, options, strategy) in cases {
+            let mut expected = input.clone();
+            expected.sort();
+            let mut arr = input;
+            assert_eq!(smart_sort_by(&mut arr, &options, Ord::cmp), strategy, "{:?}", options);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_smart_sort_respects_stability() {
+        let keys = pseudo_random(5000, 50, 47);
+        let tagged: Vec<(u64, usize)> = keys.into_iter().zip(0..).collect();
+        let mut expected = tagged.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        for constant_memory in [false, true] {
+            let options = SortOptions { stable: true, constant_memory };
+            let mut arr = tagged.clone();
+            smart_sort_by_key(&mut arr, &options, |&(key, _)| key);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_smart_sort_large_elements() {
+        // Insertion sort moves elements often, so large ones only get it on very short slices
+        let mut arr: Vec<[u64; 16]> = pseudo_random(20, 100, 53).into_iter().map(|x| [x; 16]).collect();
+        assert_eq!(smart_sort(&mut arr), SortStrategy::QuickSort);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut arr: Vec<[u64; 16]> = (0..100).map(|x| [x; 16]).collect();
+        assert_eq!(smart_sort(&mut arr), SortStrategy::TimSort);
+        assert_eq!(smart_sort(&mut arr[..8]), SortStrategy::InsertionSort);
+    }
+}
+
+/// Module containing unit tests for the `Sorter` trait and its stability contract.
+#[cfg(test)]
+mod sorter_tests {
+    use super::*;
+
+    /// Builds records with many duplicate keys, each tagged with its original position.
+    fn tagged_duplicates(len: usize, distinct_keys: u64, seed: u64) -> Vec<(u64, usize)> {
+        pseudo_random(len, distinct_keys, seed).into_iter().zip(0..).collect()
+    }
+
+    /// Returns whether sorting `input` by key alone kept equal keys in their original order.
+    fn sorts_stably<S: Sorter>(sorter: &S, input: &[(u64, usize)]) -> bool {
+        let mut arr = input.to_vec();
+        sorter.sort_by_key(&mut arr, |&(key, _)| key);
+        assert!(arr.windows(2).all(|w| w[0].0 <= w[1].0), "{} did not sort", sorter.name());
+        arr.windows(2).all(|w| w[0].0 < w[1].0 || w[0].1 < w[1].1)
+    }
+
+    /// Checks a sorter's stability claim: every sorter must sort inputs full of equal keys, and
+    /// a stable one must keep those keys in their original order. An unstable sorter is free to
+    /// keep them in order too, so only the stable direction is checked.
+    fn check_stability_contract<S: Sorter>(sorter: S) {
+        let inputs: Vec<Vec<(u64, usize)>> = [(2, 1), (5, 2), (100, 3), (1000, 4)]
+            .iter()
+            .flat_map(|&(len, seed)| [2, 10].map(|keys| tagged_duplicates(len, keys, seed)))
+            .collect();
+        for input in &inputs {
+            let stable = sorts_stably(&sorter, input);
+            if sorter.is_stable() {
+                assert!(stable, "{} claims to be stable but reordered equal keys", sorter.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sorter_stability_contract() {
+        check_stability_contract(MergeSort);
+        check_stability_contract(HeapSort);
+        check_stability_contract(QuickSort);
+        check_stability_contract(InsertionSort);
+        check_stability_contract(SelectionSort);
+        check_stability_contract(BubbleSort);
+        check_stability_contract(ShellSort);
+        check_stability_contract(CombSort);
+        check_stability_contract(CocktailShakerSort);
+        check_stability_contract(GnomeSort);
+        check_stability_contract(CycleSort);
+        check_stability_contract(OddEvenSort);
+    }
+
+    #[test]
+    fn test_sorter_complexities() {
+        assert_eq!(ShellSort.worst_case(), Complexity::Quadratic);
+        assert_eq!(ShellSort.average_case(), Complexity::Subquadratic);
+        assert!(Complexity::Linearithmic < Complexity::Subquadratic);
+        assert!(Complexity::Subquadratic < Complexity::Quadratic);
+        assert_eq!(Complexity::Subquadratic.to_string(), "o(n^2)");
+    }
+
+    #[test]
+    fn test_stable_sort_is_stable() {
+        for (len, keys) in [(0, 1), (1, 1), (1000, 2), (10_000, 50), (10_000, 5000)] {
+            let input = tagged_duplicates(len, keys, len as u64);
+            let mut expected = input.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            let mut arr = input.clone();
+            stable_sort_by_key(&mut arr, |&(key, _)| key);
+            assert_eq!(arr, expected);
+
+            let mut arr = input;
+            stable_sort_by(&mut arr, |a, b| b.0.cmp(&a.0));
+            expected.reverse();
+            // Reversing the key order must not reverse the order of equal keys
+            expected.chunk_by_mut(|a, b| a.0 == b.0).for_each(|run| run.reverse());
+            assert_eq!(arr, expected);
+        }
+
+        let mut arr = [3, 1, 2];
+        stable_sort(&mut arr);
+        assert_eq!(arr, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_sorter_metadata() {
+        let sorters: [&dyn Sorter; 6] = [&MergeSort, &HeapSort, &QuickSort, &InsertionSort, &SelectionSort, &BubbleSort];
+        let names: Vec<&str> = sorters.iter().map(|s| s.name()).collect();
+        assert_eq!(
+            names,
+            ["merge sort", "heap sort", "quick sort", "insertion sort", "selection sort", "bubble sort"]
+        );
+
+        let in_place: Vec<bool> = sorters.iter().map(|s| s.is_in_place()).collect();
+        assert_eq!(in_place, [false, true, true, true, true, true]);
+        assert!(sorters.iter().all(|s| s.average_case() <= s.worst_case()));
+        assert_eq!(QuickSort.worst_case().to_string(), "O(n log n)");
+        assert_eq!(BubbleSort.average_case().to_string(), "O(n^2)");
+
+        let mut arr = ["cc", "a", "bbb"];
+        InsertionSort.sort(&mut arr);
+        assert_eq!(arr, ["a", "bbb", "cc"]);
+        HeapSort.sort_by_key(&mut arr, |s| s.len());
+        assert_eq!(arr, ["a", "cc", "bbb"]);
+    }
+}
+
+/// Module containing unit tests for the string sorting algorithms.
+#[cfg(test)]
+mod strings_tests {
+    use super::*;
+
+    /// Builds strings over a small alphabet, with many shared prefixes, duplicates and
+    /// strings that are prefixes of others.
+    fn words(len: usize, seed: u64) -> Vec<String> {
+        let lengths = pseudo_random(len, 12, seed);
+        let mut letters = pseudo_random(len * 12, 3, seed + 1).into_iter();
+        lengths
+            .into_iter()
+            .map(|n| (0..n).map(|_| (b'a' + letters.next().unwrap() as u8) as char).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_string_sorts() {
+        type StringSort = fn(&mut [String]);
+        let sorts: [(&str, StringSort); 2] = [("multikey", multikey_quick_sort), ("radix", string_radix_sort)];
+        for (name, sort) in sorts {
+            for len in [0, 1, 2, 32, 33, 100, 5000] {
+                let mut arr = words(len, len as u64);
+                let mut expected = arr.clone();
+                expected.sort();
+                sort(&mut arr);
+                assert_eq!(arr, expected, "{} sort failed on {} words", name, len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_string_sorts_on_bytes_and_str() {
+        let mut bytes: Vec<&[u8]> = vec![b"\xff", b"", b"a\x00", b"a", b"\x00"];
+        let expected: Vec<&[u8]> = vec![b"", b"\x00", b"a", b"a\x00", b"\xff"];
+        string_radix_sort(&mut bytes);
+        assert_eq!(bytes, expected);
+        bytes.reverse();
+        multikey_quick_sort(&mut bytes);
+        assert_eq!(bytes, expected);
+
+        // Byte order matches `str` order, including for multi-byte characters
+        let mut strs = ["éclair", "zebra", "apple", "Zoo", "日本", "ångström"];
+        let mut expected = strs;
+        expected.sort();
+        multikey_quick_sort(&mut strs);
+        assert_eq!(strs, expected);
+    }
+
+    #[test]
+    fn test_string_sorts_long_shared_prefixes() {
+        // Strings that each extend the previous one would recurse once per byte
+        let prefix = "x".repeat(50_000);
+        let mut arr: Vec<String> = (0..200).rev().map(|i| format!("{}{}", prefix, "y".repeat(i))).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+
+        let mut radix = arr.clone();
+        string_radix_sort(&mut radix);
+        assert_eq!(radix, expected);
+        multikey_quick_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+}
+
+/// Module containing unit tests for float sorting.
+#[cfg(test)]
+mod floats_tests {
+    use super::*;
+
+    /// Builds floats covering every class of value: both zeros, both infinities, NaNs of both
+    /// signs, subnormals and ordinary numbers with duplicates.
+    fn special_f64s(len: usize, seed: u64) -> Vec<f64> {
+        let specials = [
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+            f64::MIN_POSITIVE / 2.0,
+            -f64::MIN_POSITIVE / 2.0,
+            f64::MAX,
+            f64::MIN,
+        ];
+        pseudo_random(len, 40, seed)
+            .into_iter()
+            .map(|r| match r as usize {
+                r if r < specials.len() => specials[r],
+                r => (r as f64 - 25.0) / 4.0,
+            })
+            .collect()
+    }
+
+    /// Compares sorted floats bit for bit, since NaN is not equal to itself.
+    fn bits<F: Float + Into<f64>>(arr: &[F]) -> Vec<u64> {
+        arr.iter().map(|&x| x.into().to_bits()).collect()
+    }
+
+    /// Sorts with a reference algorithm and checks where the NaNs went.
+    fn expected<F: Float>(input: &[F], nans: NanPlacement) -> Vec<F> {
+        let mut expected = input.to_vec();
+        expected.sort_by(|a, b| compare_floats(a, b, nans));
+        let nan_count = input.iter().filter(|x| x.is_nan()).count();
+        let nan_range = match nans {
+            NanPlacement::First => 0..nan_count,
+            NanPlacement::Last => input.len() - nan_count..input.len(),
+        };
+        assert!(expected[nan_range].iter().all(|x| x.is_nan()));
+        expected
+    }
+
+    fn check_sorter<S: Sorter>(sorter: S) {
+        for nans in [NanPlacement::First, NanPlacement::Last] {
+            for len in [0, 1, 10, 500] {
+                let input = special_f64s(len, len as u64 + 61);
+                let mut arr = input.clone();
+                sort_floats_with(&sorter, &mut arr, nans);
+                assert_eq!(bits(&arr), bits(&expected(&input, nans)), "{} {:?}", sorter.name(), nans);
+
+                let input: Vec<f32> = input.iter().map(|&x| x as f32).collect();
+                let mut arr = input.clone();
+                sort_floats_with(&sorter, &mut arr, nans);
+                assert_eq!(bits(&arr), bits(&expected(&input, nans)), "{} {:?}", sorter.name(), nans);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_floats_with_every_algorithm() {
+        check_sorter(MergeSort);
+        check_sorter(HeapSort);
+        check_sorter(QuickSort);
+        check_sorter(InsertionSort);
+        check_sorter(SelectionSort);
+        check_sorter(BubbleSort);
+    }
+
+    #[test]
+    fn test_radix_sort_floats() {
+        for nans in [NanPlacement::First, NanPlacement::Last] {
+            for len in [0, 1, 2, 10, 1000, 10_000] {
+                let input = special_f64s(len, len as u64 + 67);
+                let mut arr = input.clone();
+                radix_sort_floats(&mut arr, nans);
+                assert_eq!(bits(&arr), bits(&expected(&input, nans)));
+
+                let mut arr = input.clone();
+                sort_floats(&mut arr, nans);
+                assert_eq!(bits(&arr), bits(&expected(&input, nans)));
+            }
+        }
+
+        // Only NaNs, in both signs
+        let mut arr = [f32::NAN, -f32::NAN, f32::NAN];
+        radix_sort_floats(&mut arr, NanPlacement::First);
+        assert!(arr[0].is_sign_negative() && arr.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_sort_by_float_key() {
+        let mut records: Vec<(usize, f64)> = special_f64s(300, 73).into_iter().enumerate().collect();
+        sort_by_float_key(&mut records, |&(_, x)| x, NanPlacement::First);
+        let keys: Vec<f64> = records.iter().map(|&(_, x)| x).collect();
+        assert_eq!(bits(&keys), bits(&expected(&keys, NanPlacement::First)));
+
+        // A stable algorithm keeps records with equal keys in order
+        let mut expected = records.clone();
+        expected.sort_by(|a, b| compare_floats(&a.1.abs(), &b.1.abs(), NanPlacement::Last));
+        sort_by_float_key_with(&MergeSort, &mut records, |&(_, x)| x.abs(), NanPlacement::Last);
+        let indices = |records: &[(usize, f64)]| records.iter().map(|&(i, _)| i).collect::<Vec<_>>();
+        assert_eq!(indices(&records), indices(&expected));
+    }
+}
+
+/// Module containing unit tests for the sorting networks.
+#[cfg(test)]
+mod networks_tests {
+    use super::*;
+
+    #[test]
+    fn test_sorting_networks_are_valid() {
+        // The fewest comparators known to sort each length
+        let sizes = [0, 0, 1, 3, 5, 9, 12, 16, 19, 25, 29, 35, 39, 45, 51, 56, 60];
+        for (len, &size) in sizes.iter().enumerate() {
+            let network = sorting_network(len).unwrap();
+            assert_eq!(network.len(), size, "network for {} elements", len);
+            assert!(network.iter().all(|&(i, j)| i < j), "network for {} elements", len);
+            assert_eq!(verify_network(len, network), Ok(()), "network for {} elements", len);
+        }
+        assert_eq!(sorting_network(MAX_NETWORK_LEN + 1), None);
+    }
+
+    #[test]
+    fn test_verify_network_finds_counterexamples() {
+        // Drop each comparator of the 6-element network in turn: every one of them is needed
+        let network = sorting_network(6).unwrap();
+        for skip in 0..network.len() {
+            let broken: Vec<Comparator> = (0..network.len()).filter(|&i| i != skip).map(|i| network[i]).collect();
+            let input = verify_network(6, &broken).expect_err("a network with 11 comparators cannot sort 6 elements");
+            let mut arr = input.clone();
+            apply_network(&mut arr, &broken);
+            assert!(arr.windows(2).any(|w| w[0] > w[1]), "{:?} was sorted", input);
+        }
+        assert_eq!(verify_network(0, &[]), Ok(()));
+        assert_eq!(verify_network(2, &[]), Err(vec![1, 0]));
+    }
+
+    #[test]
+    fn test_network_sort() {
+        for len in 0..=MAX_NETWORK_LEN {
+            for (seed, modulus) in [(1, 3), (2, 100), (3, u64::MAX)] {
+                let mut arr = pseudo_random(len, modulus, seed + len as u64);
+                let mut expected = arr.clone();
+                expected.sort();
+                network_sort(&mut arr);
+                assert_eq!(arr, expected);
+
+                network_sort_by(&mut arr, |a, b| b.cmp(a));
+                expected.reverse();
+                assert_eq!(arr, expected);
+            }
+        }
+
+        let mut arr = ["ccc", "a", "bb"];
+        network_sort_by_key(&mut arr, |s| s.len());
+        assert_eq!(arr, ["a", "bb", "ccc"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_network_sort_too_long() {
+        network_sort(&mut [0; MAX_NETWORK_LEN + 1]);
+    }
+
+    #[test]
+    fn test_bitonic_sort() {
+        for log_len in 0..=4 {
+            let len = 1 << log_len;
+            let network = bitonic_network(len);
+            assert_eq!(network.len(), len * log_len * (log_len + 1) / 4);
+            assert_eq!(verify_network(len, &network), Ok(()));
+        }
+        assert!(bitonic_network(0).is_empty());
+
+        for len in [0, 1, 2, 64, 1024] {
+            let input = pseudo_random(len, 50, len as u64);
+            let mut expected = input.clone();
+            expected.sort();
+            let mut arr = input;
+            bitonic_sort(&mut arr);
+            assert_eq!(arr, expected);
+
+            bitonic_sort_by_key(&mut arr, |&x| std::cmp::Reverse(x));
+            expected.reverse();
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bitonic_sort_not_power_of_two() {
+        bitonic_sort(&mut [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_small_sort_base_case() {
+        for small_sort in [SmallSort::InsertionSort, SmallSort::SortingNetwork] {
+            for len in [0, 1, 15, 16, 17, 100, 5000] {
+                let input = pseudo_random(len, len as u64 / 3 + 1, 71);
+                let mut expected = input.clone();
+                expected.sort();
+
+                let mut arr = input.clone();
+                merge_sort_with_small_sort(&mut arr, small_sort);
+                assert_eq!(arr, expected, "merge sort with {:?}", small_sort);
+
+                let mut arr = input;
+                quick_sort_with_small_sort(&mut arr, small_sort);
+                assert_eq!(arr, expected, "quick sort with {:?}", small_sort);
+            }
+        }
+
+        // Insertion sort keeps merge sort stable
+        let tagged: Vec<(u64, usize)> = pseudo_random(1000, 10, 73).into_iter().zip(0..).collect();
+        let mut expected = tagged.clone();
+        expected.sort_by_key(|&(key, _)| key);
+        let mut arr = tagged;
+        merge_sort_with_small_sort_by(&mut arr, SmallSort::InsertionSort, |a, b| a.0.cmp(&b.0));
+        assert_eq!(arr, expected);
+
+        let mut arr: Vec<u64> = (0..1000).collect();
+        quick_sort_with_small_sort_by(&mut arr, SmallSort::SortingNetwork, |a, b| b.cmp(a));
+        assert!(arr.windows(2).all(|w| w[0] > w[1]));
+    }
+}
+
+/// Module containing unit tests for sorting linked lists and `VecDeque`s.
+#[cfg(test)]
+mod collections_tests {
+    use super::*;
+    use std::collections::{LinkedList, VecDeque};
+
+    /// Builds a deque whose elements wrap around the end of its ring buffer, with `front_len`
+    /// of them in the first slice.
+    fn wrapped_deque<T>(values: Vec<T>, front_len: usize) -> VecDeque<T> {
+        let mut deque = VecDeque::with_capacity(values.len());
+        let mut values = values.into_iter();
+        let front: Vec<T> = values.by_ref().take(front_len).collect();
+        deque.extend(values);
+        for value in front.into_iter().rev() {
+            deque.push_front(value);
+        }
+        deque
+    }
+
+    #[test]
+    fn test_sort_linked_list() {
+        for len in [0, 1, 2, 3, 7, 8, 9, 1000, 4097] {
+            let values = pseudo_random(len, len as u64 / 2 + 1, 79);
+            let mut expected = values.clone();
+            expected.sort();
+
+            let mut list: LinkedList<u64> = values.into_iter().collect();
+            sort_linked_list(&mut list);
+            assert!(list.iter().eq(&expected), "length {}", len);
+
+            sort_linked_list_by(&mut list, |a, b| b.cmp(a));
+            assert!(list.iter().eq(expected.iter().rev()), "length {}", len);
+        }
+    }
+
+    #[test]
+    fn test_sort_linked_list_is_stable() {
+        let tagged: Vec<(u64, usize)> = pseudo_random(3000, 20, 83).into_iter().zip(0..).collect();
+        let mut expected = tagged.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut list: LinkedList<(u64, usize)> = tagged.into_iter().collect();
+        sort_linked_list_by_key(&mut list, |&(key, _)| key);
+        assert!(list.into_iter().eq(expected));
+    }
+
+    #[test]
+    fn test_sort_linked_list_relinks_nodes() {
+        // Deliberately neither `Clone` nor `Copy`
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Handle(u64);
+
+        let mut list: LinkedList<Handle> = pseudo_random(500, 100, 89).into_iter().map(Handle).collect();
+        let mut addresses: Vec<*const Handle> = list.iter().map(|h| h as *const Handle).collect();
+        sort_linked_list(&mut list);
+        assert!(list.iter().zip(list.iter().skip(1)).all(|(a, b)| a <= b));
+
+        // Every element is still in the node it started in
+        let mut sorted_addresses: Vec<*const Handle> = list.iter().map(|h| h as *const Handle).collect();
+        addresses.sort();
+        sorted_addresses.sort();
+        assert_eq!(addresses, sorted_addresses);
+    }
+
+    #[test]
+    fn test_sort_linked_list_panic_safety() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let values = pseudo_random(300, 50, 97);
+        for panic_after in [1, 10, 100, 1000] {
+            let mut list: LinkedList<u64> = values.iter().copied().collect();
+            let mut comparisons = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                sort_linked_list_by(&mut list, |a, b| {
+                    comparisons += 1;
+                    if comparisons == panic_after {
+                        panic!("comparator failure");
+                    }
+                    a.cmp(b)
+                });
+            }));
+            assert!(result.is_err());
+
+            let mut remaining: Vec<u64> = list.into_iter().collect();
+            let mut original = values.clone();
+            remaining.sort();
+            original.sort();
+            assert_eq!(remaining, original);
+        }
+    }
+
+    #[test]
+    fn test_sort_vec_deque() {
+        for (len, front_len) in [(0, 0), (1, 1), (2, 1), (10, 3), (10, 9), (1000, 1), (1000, 500), (1001, 999)] {
+            for modulus in [2, 1000] {
+                let values = pseudo_random(len, modulus, len as u64 + front_len as u64);
+                let mut expected = values.clone();
+                expected.sort();
+
+                let mut deque = wrapped_deque(values, front_len);
+                let capacity = deque.capacity();
+                sort_vec_deque(&mut deque);
+                assert_eq!(deque, expected, "length {} with {} in front", len, front_len);
+
+                // Sorted in place: the ring buffer was neither reallocated nor rearranged
+                assert_eq!(deque.capacity(), capacity);
+                assert_eq!(deque.as_slices().0.len(), front_len);
+            }
+        }
+
+        // Everything in the first slice already belongs before the second
+        let mut deque = wrapped_deque(vec![3, 1, 2, 6, 4, 5], 3);
+        sort_vec_deque_by(&mut deque, |a, b| a.cmp(b));
+        assert_eq!(deque, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_sort_vec_deque_is_stable() {
+        for front_len in [0, 1, 700, 1999] {
+            let tagged: Vec<(u64, usize)> = pseudo_random(2000, 7, 101).into_iter().zip(0..).collect();
+            let mut expected = tagged.clone();
+            expected.sort_by_key(|&(key, _)| key);
+
+            let mut deque = wrapped_deque(tagged, front_len);
+            sort_vec_deque_by_key(&mut deque, |&(key, _)| key);
+            assert_eq!(deque, expected, "{} in front", front_len);
+        }
+    }
+}